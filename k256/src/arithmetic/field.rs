@@ -1,6 +1,6 @@
 //! Field arithmetic modulo p = 2^256 - 2^32 - 2^9 - 2^8 - 2^7 - 2^6 - 2^4 - 1
 
-#![allow(clippy::assign_op_pattern, clippy::op_ref)]
+#![allow(clippy::assign_op_pattern, clippy::needless_range_loop, clippy::op_ref)]
 
 use cfg_if::cfg_if;
 
@@ -67,12 +67,10 @@ impl Field for FieldElement {
         Self::ONE
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         self.square()
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.double()
     }
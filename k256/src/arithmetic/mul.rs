@@ -435,7 +435,7 @@ mod tests {
         let k = Scalar::random(&mut OsRng);
         let l = Scalar::random(&mut OsRng);
 
-        let reference = &x * &k + &y * &l;
+        let reference = x * k + y * l;
         let test = ProjectivePoint::lincomb(&x, &k, &y, &l);
         assert_eq!(reference, test);
     }
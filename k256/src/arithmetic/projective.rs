@@ -1,6 +1,6 @@
 //! Projective points
 
-#![allow(clippy::op_ref)]
+#![allow(clippy::needless_range_loop, clippy::op_ref)]
 
 use super::{AffinePoint, FieldElement, Scalar, CURVE_EQUATION_B_SINGLE};
 use crate::{CompressedPoint, EncodedPoint, PublicKey, Secp256k1};
@@ -310,7 +310,6 @@ impl Group for ProjectivePoint {
         self.ct_eq(&Self::IDENTITY)
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         Self::double(self)
     }
@@ -519,7 +518,7 @@ impl Neg for ProjectivePoint {
     }
 }
 
-impl<'a> Neg for &'a ProjectivePoint {
+impl Neg for &ProjectivePoint {
     type Output = ProjectivePoint;
 
     fn neg(self) -> ProjectivePoint {
@@ -244,12 +244,10 @@ impl Field for Scalar {
         Self::ONE
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         Scalar::square(self)
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.add(self)
     }
@@ -263,7 +261,7 @@ impl Field for Scalar {
     #[allow(clippy::many_single_char_names)]
     fn sqrt(&self) -> CtOption<Self> {
         // Note: `pow_vartime` is constant-time with respect to `self`
-        let w = self.pow_vartime(&[
+        let w = self.pow_vartime([
             0x777fa4bd19a06c82,
             0xfd755db9cd5e9140,
             0xffffffffffffffff,
@@ -755,7 +753,7 @@ mod tests {
 
         let a = Scalar::from(&t - &one);
         let b = Scalar::from(&t);
-        let res = &a + &b;
+        let res = a + b;
 
         let m = Scalar::modulus_as_biguint();
         let res_ref = Scalar::from((&t + &t - &one) % &m);
@@ -768,7 +766,7 @@ mod tests {
         use elliptic_curve::rand_core::OsRng;
         let a = Scalar::generate_biased(&mut OsRng);
         // just to make sure `a` is not optimized out by the compiler
-        assert_eq!((a - &a).is_zero().unwrap_u8(), 1);
+        assert_eq!((a - a).is_zero().unwrap_u8(), 1);
     }
 
     #[test]
@@ -776,7 +774,7 @@ mod tests {
         use elliptic_curve::rand_core::OsRng;
         let a = Scalar::generate_vartime(&mut OsRng);
         // just to make sure `a` is not optimized out by the compiler
-        assert_eq!((a - &a).is_zero().unwrap_u8(), 1);
+        assert_eq!((a - a).is_zero().unwrap_u8(), 1);
     }
 
     #[test]
@@ -181,8 +181,8 @@ impl signature::PrehashSignature for Signature {
 fn tagged_hash(tag: &[u8]) -> Sha256 {
     let tag_hash = Sha256::digest(tag);
     let mut digest = Sha256::new();
-    digest.update(&tag_hash);
-    digest.update(&tag_hash);
+    digest.update(tag_hash);
+    digest.update(tag_hash);
     digest
 }
 
@@ -91,8 +91,8 @@ impl SigningKey {
         }
 
         let rand = tagged_hash(NONCE_TAG)
-            .chain_update(&t)
-            .chain_update(&self.verifying_key.as_affine().x.to_bytes())
+            .chain_update(t)
+            .chain_update(self.verifying_key.as_affine().x.to_bytes())
             .chain_update(msg_digest)
             .finalize();
 
@@ -103,8 +103,8 @@ impl SigningKey {
 
         let e = <Scalar as Reduce<U256>>::from_be_bytes_reduced(
             tagged_hash(CHALLENGE_TAG)
-                .chain_update(&r.to_bytes())
-                .chain_update(&self.verifying_key.to_bytes())
+                .chain_update(r.to_bytes())
+                .chain_update(self.verifying_key.to_bytes())
                 .chain_update(msg_digest)
                 .finalize(),
         );
@@ -42,7 +42,7 @@ impl VerifyingKey {
 
         let R = ProjectivePoint::lincomb(
             &ProjectivePoint::GENERATOR,
-            &*s,
+            s,
             &self.inner.to_projective(),
             &-e,
         )
@@ -0,0 +1,107 @@
+//! secp256r1 ECDSA verification benchmarks
+
+use criterion::{
+    criterion_group, criterion_main, measurement::Measurement, BenchmarkGroup, Criterion,
+};
+use hex_literal::hex;
+use p256::ecdsa::{
+    signature::{DigestVerifier, Signer},
+    Signature, SigningKey, SigningKeyWithCache, VerifyingKey,
+};
+use sha2::{Digest, Sha256};
+
+const MESSAGE: &[u8] =
+    b"ECDSA proves knowledge of a secret number in the context of a single message";
+
+fn test_signing_key() -> SigningKey {
+    SigningKey::from_bytes(&hex!(
+        "519b423d715f8b581f4fa8ee59f4771a5b44c8130b4e3eacca54a56dda72b464"
+    ))
+    .unwrap()
+}
+
+fn bench_verify_digest<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let signing_key = test_signing_key();
+    let verifying_key = VerifyingKey::from(&signing_key);
+    let signature: Signature = signing_key.sign(MESSAGE);
+    let digest = Sha256::new_with_prefix(MESSAGE);
+
+    group.bench_function("verify_digest", |b| {
+        b.iter(|| verifying_key.verify_digest(digest.clone(), &signature))
+    });
+}
+
+fn bench_verify_digest_precomputed<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let signing_key = test_signing_key();
+    let verifying_key = VerifyingKey::from(&signing_key).precompute();
+    let signature: Signature = signing_key.sign(MESSAGE);
+    let digest = Sha256::new_with_prefix(MESSAGE);
+
+    group.bench_function("verify_digest (precomputed)", |b| {
+        b.iter(|| verifying_key.verify_digest(digest.clone(), &signature))
+    });
+}
+
+fn bench_verify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ecdsa verification");
+    bench_verify_digest(&mut group);
+    bench_verify_digest_precomputed(&mut group);
+    group.finish();
+}
+
+fn bench_verifying_key(c: &mut Criterion) {
+    let mut group = c.benchmark_group("verifying key access");
+    let signing_key = test_signing_key();
+    let cached = SigningKeyWithCache::new(signing_key.clone());
+
+    group.bench_function("SigningKey::verifying_key", |b| {
+        b.iter(|| signing_key.verifying_key())
+    });
+
+    group.bench_function("SigningKeyWithCache::verifying_key", |b| {
+        b.iter(|| *cached.verifying_key())
+    });
+
+    group.finish();
+}
+
+#[cfg(all(feature = "alloc", feature = "keccak256"))]
+fn bench_verify_eth(c: &mut Criterion) {
+    use p256::{ecdsa::recoverable, FieldBytes};
+
+    let signing_key = test_signing_key();
+    let items = (0..256u32)
+        .map(|i| {
+            let msg = i.to_be_bytes();
+            let signature: recoverable::Signature = signing_key.sign(&msg);
+            let msg_hash: [u8; 32] = Sha256::digest(msg).into();
+            let address = signature.recover_eth_address(&msg_hash).unwrap();
+            let r = FieldBytes::clone_from_slice(&signature.as_ref()[..32]);
+            let s = FieldBytes::clone_from_slice(&signature.as_ref()[32..64]);
+            (address, msg_hash, r, s, signature.as_ref()[64] + 27)
+        })
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("ethereum signature verification (256 items)");
+
+    group.bench_function("verify_eth (sequential)", |b| {
+        b.iter(|| {
+            items.iter().all(|(address, msg_hash, r, s, v)| {
+                recoverable::verify_eth(msg_hash, r, s, *v, address).is_ok()
+            })
+        })
+    });
+
+    group.bench_function("verify_eth_batch", |b| {
+        b.iter(|| recoverable::verify_eth_batch(&items))
+    });
+
+    group.finish();
+}
+
+#[cfg(all(feature = "alloc", feature = "keccak256"))]
+criterion_group!(benches, bench_verify, bench_verifying_key, bench_verify_eth);
+
+#[cfg(not(all(feature = "alloc", feature = "keccak256")))]
+criterion_group!(benches, bench_verify, bench_verifying_key);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+//! Parse arbitrary bytes as a recoverable signature and exercise every
+//! accessor on the result, none of which may panic.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use p256::{ecdsa::recoverable, FieldBytes};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = recoverable::Signature::from_bytes_strict(data);
+
+    if let Ok(bytes) = <&[u8; recoverable::SIZE]>::try_from(data) {
+        if let Ok((signature, _)) = recoverable::Signature::from_bitcoin_message_sig(bytes) {
+            exercise(signature);
+        }
+    }
+
+    if let Ok(signature) = recoverable::Signature::try_from(data) {
+        exercise(signature);
+    }
+});
+
+fn exercise(signature: recoverable::Signature) {
+    let _ = signature.recovery_id();
+    let _ = signature.r();
+    let _ = signature.s();
+    let _ = p256::ecdsa::Signature::from(signature);
+    let _ = signature.recover_verifying_key_from_digest_bytes(&FieldBytes::default());
+    let _ = signature.recover_verifying_key(b"fuzz");
+}
@@ -3,6 +3,7 @@
 pub(crate) mod field;
 #[cfg(feature = "hash2curve")]
 mod hash2curve;
+pub(crate) mod point;
 pub(crate) mod scalar;
 pub(crate) mod util;
 
@@ -19,6 +20,10 @@ pub type AffinePoint = weierstrass::AffinePoint<NistP256>;
 /// Elliptic curve point in projective coordinates.
 pub type ProjectivePoint = weierstrass::ProjectivePoint<NistP256>;
 
+/// Elliptic curve point along with a precomputed 4-bit window table of its
+/// multiples.
+pub type PrecomputedPoint = weierstrass::PrecomputedPoint<NistP256, 16>;
+
 impl WeierstrassCurve for NistP256 {
     type FieldElement = FieldElement;
 
@@ -26,14 +31,9 @@ impl WeierstrassCurve for NistP256 {
     const ONE: FieldElement = FieldElement::ONE;
 
     /// a = -3
-    const EQUATION_A: FieldElement = FieldElement::ZERO
-        .sub(&FieldElement::ONE)
-        .sub(&FieldElement::ONE)
-        .sub(&FieldElement::ONE);
+    const EQUATION_A: FieldElement = FieldElement::A;
 
-    const EQUATION_B: FieldElement = FieldElement::from_be_hex(
-        "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
-    );
+    const EQUATION_B: FieldElement = FieldElement::B;
 
     /// Base point of P-256.
     ///
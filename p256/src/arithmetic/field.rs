@@ -1,6 +1,6 @@
 //! Field arithmetic modulo p = 2^{224}(2^{32} − 1) + 2^{192} + 2^{96} − 1
 
-#![allow(clippy::assign_op_pattern, clippy::op_ref)]
+#![allow(clippy::assign_op_pattern, clippy::needless_range_loop, clippy::op_ref)]
 
 #[cfg_attr(target_pointer_width = "32", path = "field/field32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "field/field64.rs")]
@@ -47,6 +47,30 @@ weierstrass::impl_field_element!(
 );
 
 impl FieldElement {
+    /// The field element `2`.
+    pub const TWO: Self = Self::from_u64(2);
+
+    /// Coefficient `a = -3` of the curve equation `y² = x³ + ax + b`.
+    pub const A: Self = Self::ZERO.sub(&Self::ONE).sub(&Self::ONE).sub(&Self::ONE);
+
+    /// Coefficient `b` of the curve equation `y² = x³ + ax + b`.
+    ///
+    /// Defined in FIPS 186-4 § D.1.2.3:
+    ///
+    /// ```text
+    /// b = 5ac635d8 aa3a93e7 b3ebbd55 769886bc 651d06b0 cc53b0f6 3bce3c3e 27d2604b
+    /// ```
+    pub const B: Self =
+        Self::from_be_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+
+    /// Create a field element from a small integer.
+    ///
+    /// Every `u64` is smaller than the modulus so no reduction is needed, and
+    /// this operation is constant time.
+    pub const fn from_u64(w: u64) -> Self {
+        Self::from_uint_unchecked(U256::from_u64(w))
+    }
+
     /// Attempts to parse the given byte array as an SEC1-encoded field element.
     ///
     /// Returns None if the byte array does not contain a big-endian integer in the range
@@ -144,7 +168,7 @@ impl FieldElement {
 
 impl From<u64> for FieldElement {
     fn from(n: u64) -> FieldElement {
-        Self::from_uint_unchecked(U256::from(n))
+        Self::from_u64(n)
     }
 }
 
@@ -187,10 +211,38 @@ impl PrimeField for FieldElement {
 #[cfg(test)]
 mod tests {
     use super::FieldElement;
-    use crate::{test_vectors::field::DBL_TEST_VECTORS, FieldBytes};
-    use elliptic_curve::{bigint::U256, ff::Field};
+    use crate::{test_vectors::field::DBL_TEST_VECTORS, AffinePoint, FieldBytes};
+    use elliptic_curve::{bigint::U256, ff::Field, sec1::ToEncodedPoint};
     use proptest::{num, prelude::*};
 
+    #[test]
+    fn generator_satisfies_curve_equation() {
+        let generator = AffinePoint::GENERATOR.to_encoded_point(false);
+        let x = FieldElement::from_sec1(*generator.x().unwrap()).unwrap();
+        let y = FieldElement::from_sec1(*generator.y().unwrap()).unwrap();
+
+        assert_eq!(FieldElement::A, -FieldElement::from(3));
+        assert_eq!(
+            y.square(),
+            x.square() * x + FieldElement::A * x + FieldElement::B
+        );
+    }
+
+    #[test]
+    fn from_u64() {
+        let one = FieldElement::ONE;
+        assert_eq!(FieldElement::from_u64(0), FieldElement::ZERO);
+        assert_eq!(FieldElement::from_u64(1), one);
+        assert_eq!(FieldElement::from_u64(2), FieldElement::TWO);
+        assert_eq!(FieldElement::TWO, one + one);
+        assert_eq!(FieldElement::from_u64(3), one + one + one);
+        assert_eq!(FieldElement::from_u64(7), FieldElement::from(7));
+        assert_eq!(
+            FieldElement::from_u64(u64::MAX).to_canonical(),
+            U256::from_u64(u64::MAX)
+        );
+    }
+
     #[test]
     fn zero_is_additive_identity() {
         let zero = FieldElement::zero();
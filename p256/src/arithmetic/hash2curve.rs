@@ -97,7 +97,7 @@ impl FromOkm for Scalar {
 
 #[cfg(test)]
 mod tests {
-    use crate::{FieldElement, NistP256, Scalar, U256};
+    use crate::{arithmetic::field::FieldElement, NistP256, Scalar, U256};
     use elliptic_curve::{
         bigint::{ArrayEncoding, NonZero, U384},
         consts::U48,
@@ -0,0 +1,249 @@
+//! Typed SEC1 encodings of NIST P-256 curve points.
+//!
+//! Each type wraps the bytes of one particular SEC1 encoding of a point on
+//! the curve other than the identity, so an API which accepts e.g. a
+//! [`ValidatedCompressedPoint`] can't be handed an uncompressed encoding by
+//! mistake. The compressed form isn't simply called `CompressedPoint`, since
+//! that name is already taken by the crate's plain byte array alias.
+//!
+//! Parsing from bytes (`TryFrom<&[u8]>`) only accepts the corresponding
+//! encoding. Conversions from an [`EncodedPoint`] or [`AffinePoint`] accept
+//! any valid non-identity point and re-encode it.
+
+use crate::{AffinePoint, EncodedPoint};
+use elliptic_curve::{
+    sec1::{FromEncodedPoint, Tag, ToEncodedPoint},
+    Error, Result,
+};
+
+/// Size of a compressed SEC1-encoded point.
+const COMPRESSED_SIZE: usize = 33;
+
+/// Size of an uncompressed or hybrid SEC1-encoded point.
+const UNCOMPRESSED_SIZE: usize = 65;
+
+/// Tag of a hybrid encoding with an even `y`-coordinate (SEC1 § 2.3.3).
+const HYBRID_EVEN_Y_TAG: u8 = 0x06;
+
+/// Tag of a hybrid encoding with an odd `y`-coordinate (SEC1 § 2.3.3).
+const HYBRID_ODD_Y_TAG: u8 = 0x07;
+
+/// Compressed SEC1-encoded NIST P-256 curve point: a `0x02` or `0x03` tag
+/// indicating the parity of `y` followed by the `x`-coordinate.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct ValidatedCompressedPoint([u8; COMPRESSED_SIZE]);
+
+/// Uncompressed SEC1-encoded NIST P-256 curve point: a `0x04` tag followed
+/// by the `x`- and `y`-coordinates.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct UncompressedPoint([u8; UNCOMPRESSED_SIZE]);
+
+/// Hybrid SEC1-encoded NIST P-256 curve point: a `0x06` or `0x07` tag
+/// indicating the parity of `y` followed by the `x`- and `y`-coordinates.
+///
+/// This encoding is rarely used, however it is still specified by SEC1 and
+/// ANSI X9.62.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct HybridPoint([u8; UNCOMPRESSED_SIZE]);
+
+impl ValidatedCompressedPoint {
+    fn from_affine(point: &AffinePoint) -> Result<Self> {
+        let mut bytes = [0u8; COMPRESSED_SIZE];
+        bytes.copy_from_slice(non_identity(point)?.to_encoded_point(true).as_bytes());
+        Ok(Self(bytes))
+    }
+
+    fn to_affine(self) -> Result<AffinePoint> {
+        decode(&self.0, &[Tag::CompressedEvenY, Tag::CompressedOddY])
+    }
+
+    fn to_encoded_point(self) -> EncodedPoint {
+        EncodedPoint::from_bytes(self.0).expect("point ensured valid in constructor")
+    }
+}
+
+impl UncompressedPoint {
+    fn from_affine(point: &AffinePoint) -> Result<Self> {
+        let mut bytes = [0u8; UNCOMPRESSED_SIZE];
+        bytes.copy_from_slice(non_identity(point)?.to_encoded_point(false).as_bytes());
+        Ok(Self(bytes))
+    }
+
+    fn to_affine(self) -> Result<AffinePoint> {
+        decode(&self.0, &[Tag::Uncompressed])
+    }
+
+    fn to_encoded_point(self) -> EncodedPoint {
+        EncodedPoint::from_bytes(self.0).expect("point ensured valid in constructor")
+    }
+}
+
+impl HybridPoint {
+    fn from_affine(point: &AffinePoint) -> Result<Self> {
+        let mut bytes = UncompressedPoint::from_affine(point)?.0;
+        bytes[0] = HYBRID_EVEN_Y_TAG | u8::from(point.is_y_odd());
+        Ok(Self(bytes))
+    }
+
+    fn to_affine(self) -> Result<AffinePoint> {
+        let y_is_odd = match self.0[0] {
+            HYBRID_EVEN_Y_TAG => false,
+            HYBRID_ODD_Y_TAG => true,
+            _ => return Err(Error),
+        };
+
+        let mut bytes = self.0;
+        bytes[0] = Tag::Uncompressed.into();
+        let point = decode(&bytes, &[Tag::Uncompressed])?;
+
+        if point.is_y_odd() == y_is_odd {
+            Ok(point)
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// [`EncodedPoint`] has no hybrid form, so this is the uncompressed
+    /// encoding of the same point.
+    fn to_encoded_point(self) -> EncodedPoint {
+        let mut bytes = self.0;
+        bytes[0] = Tag::Uncompressed.into();
+        EncodedPoint::from_bytes(bytes).expect("point ensured valid in constructor")
+    }
+}
+
+/// Reject the identity, which has no compressed, uncompressed or hybrid
+/// encoding.
+fn non_identity(point: &AffinePoint) -> Result<&AffinePoint> {
+    if point.is_identity().into() {
+        Err(Error)
+    } else {
+        Ok(point)
+    }
+}
+
+/// Decode a SEC1-encoded point whose tag is one of `tags`.
+fn decode(bytes: &[u8], tags: &[Tag]) -> Result<AffinePoint> {
+    let encoded_point = EncodedPoint::from_bytes(bytes).map_err(|_| Error)?;
+
+    if !tags.contains(&encoded_point.tag()) {
+        return Err(Error);
+    }
+
+    Option::from(AffinePoint::from_encoded_point(&encoded_point)).ok_or(Error)
+}
+
+macro_rules! impl_point_encoding {
+    ($point:ident, $size:expr) => {
+        impl $point {
+            /// Borrow the encoded point as a byte array.
+            pub fn as_bytes(&self) -> &[u8; $size] {
+                &self.0
+            }
+        }
+
+        impl AsRef<[u8]> for $point {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl From<$point> for [u8; $size] {
+            fn from(point: $point) -> [u8; $size] {
+                point.0
+            }
+        }
+
+        impl From<$point> for AffinePoint {
+            fn from(point: $point) -> AffinePoint {
+                AffinePoint::from(&point)
+            }
+        }
+
+        impl From<&$point> for AffinePoint {
+            fn from(point: &$point) -> AffinePoint {
+                point
+                    .to_affine()
+                    .expect("point ensured valid in constructor")
+            }
+        }
+
+        impl From<$point> for EncodedPoint {
+            fn from(point: $point) -> EncodedPoint {
+                point.to_encoded_point()
+            }
+        }
+
+        impl From<&$point> for EncodedPoint {
+            fn from(point: &$point) -> EncodedPoint {
+                point.to_encoded_point()
+            }
+        }
+
+        impl TryFrom<AffinePoint> for $point {
+            type Error = Error;
+
+            fn try_from(point: AffinePoint) -> Result<Self> {
+                Self::from_affine(&point)
+            }
+        }
+
+        impl TryFrom<&AffinePoint> for $point {
+            type Error = Error;
+
+            fn try_from(point: &AffinePoint) -> Result<Self> {
+                Self::from_affine(point)
+            }
+        }
+
+        impl TryFrom<EncodedPoint> for $point {
+            type Error = Error;
+
+            fn try_from(encoded_point: EncodedPoint) -> Result<Self> {
+                Self::try_from(&encoded_point)
+            }
+        }
+
+        impl TryFrom<&EncodedPoint> for $point {
+            type Error = Error;
+
+            fn try_from(encoded_point: &EncodedPoint) -> Result<Self> {
+                let point =
+                    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(encoded_point))
+                        .ok_or(Error)?;
+
+                Self::from_affine(&point)
+            }
+        }
+
+        impl TryFrom<&[u8]> for $point {
+            type Error = Error;
+
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                let point = Self(bytes.try_into().map_err(|_| Error)?);
+                point.to_affine()?;
+                Ok(point)
+            }
+        }
+    };
+}
+
+macro_rules! impl_point_conversion {
+    ($from:ident => $($to:ident),+) => {
+        $(
+            impl From<$from> for $to {
+                fn from(point: $from) -> $to {
+                    $to::from_affine(&point.into()).expect("point ensured valid in constructor")
+                }
+            }
+        )+
+    };
+}
+
+impl_point_encoding!(ValidatedCompressedPoint, COMPRESSED_SIZE);
+impl_point_encoding!(UncompressedPoint, UNCOMPRESSED_SIZE);
+impl_point_encoding!(HybridPoint, UNCOMPRESSED_SIZE);
+
+impl_point_conversion!(ValidatedCompressedPoint => UncompressedPoint, HybridPoint);
+impl_point_conversion!(UncompressedPoint => ValidatedCompressedPoint, HybridPoint);
+impl_point_conversion!(HybridPoint => ValidatedCompressedPoint, UncompressedPoint);
@@ -11,7 +11,6 @@ use crate::{FieldBytes, NistP256, SecretKey};
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use elliptic_curve::{
     bigint::{prelude::*, Limb, U256},
-    generic_array::arr,
     group::ff::{Field, PrimeField},
     ops::{Reduce, ReduceNonZero},
     rand_core::RngCore,
@@ -23,9 +22,15 @@ use elliptic_curve::{
     Curve, IsHigh, ScalarCore,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "bits")]
 use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
 
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
@@ -36,6 +41,16 @@ pub(crate) const MODULUS: U256 = NistP256::ORDER;
 /// `MODULUS / 2`
 const FRAC_MODULUS_2: Scalar = Scalar(MODULUS.shr_vartime(1));
 
+/// `R = 2^256 mod n`, the Montgomery form of one.
+const MONTGOMERY_R: Scalar = Scalar(U256::from_be_hex(
+    "00000000ffffffff00000000000000004319055258e8617b0c46353d039cdaaf",
+));
+
+/// `R^-1 = 2^-256 mod n`.
+const MONTGOMERY_R_INV: Scalar = Scalar(U256::from_be_hex(
+    "60d066334905c1e907f8b6041e607725badef3e243566fafce1bc8f79c197c79",
+));
+
 /// MU = floor(2^512 / n)
 ///    = 115792089264276142090721624801893421302707618245269942344307673200490803338238
 ///    = 0x100000000fffffffffffffffeffffffff43190552df1a6c21012ffd85eedf9bfe
@@ -89,12 +104,135 @@ impl Scalar {
     /// Multiplicative identity.
     pub const ONE: Self = Self(U256::ONE);
 
+    /// Inverse of 2, i.e. `(n + 1) / 2`.
+    pub const TWO_INV: Self = Self(U256::from_be_hex(
+        "7fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a9",
+    ));
+
+    /// Generator of the multiplicative group of the scalar field.
+    pub const MULTIPLICATIVE_GENERATOR: Self = Self(U256::from_u64(7));
+
+    /// `2^S`-th root of unity, i.e. `MULTIPLICATIVE_GENERATOR^t` where
+    /// `n - 1 = 2^S * t` with `t` odd.
+    pub const ROOT_OF_UNITY: Self = Self(U256::from_be_hex(
+        "ffc97f062a770992ba807ace842a3dfc1546cad004378daf0592d7fbb41e6602",
+    ));
+
+    /// Inverse of [`Scalar::ROOT_OF_UNITY`].
+    pub const ROOT_OF_UNITY_INV: Self = Self(U256::from_be_hex(
+        "a0a66a5562d46f2ac645fa0458131caee3ac117c794c4137379c7f0657c73764",
+    ));
+
+    /// `MULTIPLICATIVE_GENERATOR^(2^S)`, which generates the subgroup of
+    /// order `t`.
+    pub const DELTA: Self = Self(U256::from_u64(0x1e39_a505_7d81));
+
+    /// 2-adicity of `n - 1`, see [`PrimeField::S`].
+    pub const S: u32 = 4;
+
     /// Returns the SEC1 encoding of this scalar.
+    ///
+    /// This is a **big endian** encoding, as used by SEC1 and the rest of
+    /// this crate. See [`Scalar::to_bytes_le`] for the little endian one.
     pub fn to_bytes(&self) -> FieldBytes {
         self.0.to_be_byte_array()
     }
 
+    /// Returns the **little endian** encoding of this scalar, i.e. the
+    /// reverse of [`Scalar::to_bytes`], as used by e.g. Curve25519
+    /// libraries.
+    pub fn to_bytes_le(&self) -> [u8; 32] {
+        self.0.to_le_byte_array().into()
+    }
+
+    /// Parse a scalar from its **little endian** encoding.
+    ///
+    /// Returns `None` if the bytes do not encode an integer in the range
+    /// `[0, n)`. Use [`PrimeField::from_repr`] for the (big endian) SEC1
+    /// encoding.
+    pub fn from_bytes_le(bytes: &[u8; 32]) -> CtOption<Self> {
+        let inner = U256::from_le_byte_array((*bytes).into());
+        CtOption::new(Self(inner), inner.ct_lt(&NistP256::ORDER))
+    }
+
+    /// Returns the canonical integer representative of this scalar, which is
+    /// always in the range `[0, n)`.
+    pub const fn to_uint(&self) -> U256 {
+        self.0
+    }
+
+    /// Create a scalar from an integer *without* reducing it modulo `n`.
+    ///
+    /// The caller is responsible for ensuring `uint < n`: arithmetic on
+    /// scalars created from out-of-range integers produces incorrect
+    /// results. Use [`Reduce::from_uint_reduced`] to reduce arbitrary
+    /// integers, or [`PrimeField::from_repr`] to reject out-of-range ones.
+    pub const fn from_uint_unchecked(uint: U256) -> Self {
+        Self(uint)
+    }
+
+    /// Returns the Montgomery form `self * 2^256 mod n` of this scalar as
+    /// little endian 64-bit limbs, e.g. for use with external Montgomery
+    /// arithmetic routines.
+    ///
+    /// ⚠️ **Unstable**: scalars in this crate are *not* stored in Montgomery
+    /// form, so this performs a (constant time) multiplication rather than
+    /// exposing the internal representation. Its details, including whether
+    /// it is a no-op, may change in any release.
+    pub fn to_montgomery_limbs(&self) -> [u64; 4] {
+        let bytes = self.mul(&MONTGOMERY_R).to_bytes_le();
+        let mut limbs = [0u64; 4];
+
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        limbs
+    }
+
+    /// Create a scalar from its Montgomery form as returned by
+    /// [`Scalar::to_montgomery_limbs`].
+    ///
+    /// ⚠️ **Unstable**, see [`Scalar::to_montgomery_limbs`]. The limbs are
+    /// *not* checked: the caller is responsible for ensuring they encode an
+    /// integer less than `n`, otherwise the result is unspecified.
+    pub fn from_montgomery_limbs_unchecked(limbs: [u64; 4]) -> Self {
+        let mut bytes = [0u8; 32];
+
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+
+        let montgomery = Self(U256::from_le_byte_array(bytes.into()));
+        montgomery.mul(&MONTGOMERY_R_INV)
+    }
+
+    /// Returns the canonical integer representative of this scalar as a
+    /// [`BigUint`].
+    #[cfg(feature = "num-bigint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+    pub fn to_biguint(&self) -> BigUint {
+        BigUint::from_bytes_be(&self.to_bytes())
+    }
+
+    /// Convert a [`BigUint`] into a scalar, returning `None` if it is not in
+    /// the range `[0, n)`.
+    ///
+    /// Unlike the other scalar operations this is not constant time, as
+    /// [`BigUint`] itself is not.
+    #[cfg(feature = "num-bigint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+    pub fn try_from_biguint(uint: &BigUint) -> Option<Self> {
+        let bytes = uint.to_bytes_be();
+        let mut repr = FieldBytes::default();
+        let offset = repr.len().checked_sub(bytes.len())?;
+        repr[offset..].copy_from_slice(&bytes);
+        Self::from_repr(repr).into()
+    }
+
     /// Returns self + rhs mod n
+    ///
+    /// Runs in constant time.
     pub const fn add(&self, rhs: &Self) -> Self {
         Self(self.0.add_mod(&rhs.0, &NistP256::ORDER))
     }
@@ -105,11 +243,22 @@ impl Scalar {
     }
 
     /// Returns self - rhs mod n.
+    ///
+    /// Runs in constant time.
     pub const fn sub(&self, rhs: &Self) -> Self {
         Self(self.0.sub_mod(&rhs.0, &NistP256::ORDER))
     }
 
+    /// Returns -self mod n.
+    ///
+    /// Runs in constant time.
+    pub const fn neg(&self) -> Self {
+        Self::sub(&Self::ZERO, self)
+    }
+
     /// Returns self * rhs mod n
+    ///
+    /// Runs in constant time.
     pub const fn mul(&self, rhs: &Self) -> Self {
         let (lo, hi) = self.0.mul_wide(&rhs.0);
         Self(barrett_reduce(lo, hi))
@@ -133,7 +282,7 @@ impl Scalar {
         // Thus inversion can be implemented with a single exponentiation.
         //
         // This is `n - 2`, so the top right two digits are `4f` instead of `51`.
-        let inverse = self.pow_vartime(&[
+        let inverse = self.pow_vartime([
             0xf3b9_cac2_fc63_254f,
             0xbce6_faad_a717_9e84,
             0xffff_ffff_ffff_ffff,
@@ -143,6 +292,40 @@ impl Scalar {
         CtOption::new(inverse, !self.is_zero())
     }
 
+    /// Invert every scalar in `scalars` in place using Montgomery's trick,
+    /// which costs a single inversion plus `3(n - 1)` multiplications
+    /// instead of `n` inversions.
+    ///
+    /// Returns the inverse of the product of all scalars, i.e. the product
+    /// of their inverses, or none if any of them is zero, in which case
+    /// `scalars` is left unmodified. Runs in constant time with respect to
+    /// the values of the scalars.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn batch_invert(scalars: &mut [Scalar]) -> CtOption<Scalar> {
+        // `prefix[i]` is the product of all scalars before `scalars[i]`
+        let mut prefix = Vec::with_capacity(scalars.len());
+        let mut acc = Scalar::ONE;
+
+        for scalar in scalars.iter() {
+            prefix.push(acc);
+            acc *= scalar;
+        }
+
+        let product_inv = acc.invert();
+        let is_some = product_inv.is_some();
+        let product_inv = product_inv.unwrap_or(Scalar::ZERO);
+        let mut acc_inv = product_inv;
+
+        for (scalar, prefix) in scalars.iter_mut().zip(prefix).rev() {
+            let inverse = acc_inv * prefix;
+            acc_inv *= *scalar;
+            scalar.conditional_assign(&inverse, is_some);
+        }
+
+        CtOption::new(product_inv, is_some)
+    }
+
     /// Faster inversion using Stein's algorithm
     #[allow(non_snake_case)]
     pub fn invert_vartime(&self) -> CtOption<Self> {
@@ -204,6 +387,22 @@ impl Scalar {
         !self.is_odd()
     }
 
+    /// Convert a message digest of arbitrary length into a [`Scalar`] as
+    /// specified in FIPS 186-4 § 6.4.
+    ///
+    /// The leftmost `bitlen(n) = 256` bits of the digest are interpreted as a
+    /// big endian integer; shorter digests are used in their entirety. Any
+    /// trailing bytes of an over-long digest (e.g. the second half of a
+    /// SHA-512 output) are discarded rather than folded into the reduction.
+    /// The resulting integer is then reduced modulo `n`.
+    pub fn from_digest_truncated(digest: &[u8]) -> Self {
+        let mut bytes = FieldBytes::default();
+        let len = digest.len().min(bytes.len());
+        let offset = bytes.len() - len;
+        bytes[offset..].copy_from_slice(&digest[..len]);
+        <Self as Reduce<U256>>::from_be_bytes_reduced(bytes)
+    }
+
     /// Shift right by one bit
     fn shr1(&mut self) {
         self.0 >>= 1;
@@ -239,12 +438,10 @@ impl Field for Scalar {
         Self::ONE
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         Scalar::square(self)
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.add(self)
     }
@@ -258,7 +455,7 @@ impl Field for Scalar {
     #[allow(clippy::many_single_char_names)]
     fn sqrt(&self) -> CtOption<Self> {
         // Note: `pow_vartime` is constant-time with respect to `self`
-        let w = self.pow_vartime(&[
+        let w = self.pow_vartime([
             0x279dce5617e3192a,
             0xfde737d56d38bcf4,
             0x07ffffffffffffff,
@@ -301,7 +498,7 @@ impl PrimeField for Scalar {
 
     const NUM_BITS: u32 = 256;
     const CAPACITY: u32 = 255;
-    const S: u32 = 4;
+    const S: u32 = Self::S;
 
     /// Attempts to parse the given byte array as an SEC1-encoded scalar.
     ///
@@ -321,16 +518,11 @@ impl PrimeField for Scalar {
     }
 
     fn multiplicative_generator() -> Self {
-        7u64.into()
+        Self::MULTIPLICATIVE_GENERATOR
     }
 
     fn root_of_unity() -> Self {
-        Scalar::from_repr(arr![u8;
-            0xff, 0xc9, 0x7f, 0x06, 0x2a, 0x77, 0x09, 0x92, 0xba, 0x80, 0x7a, 0xce, 0x84, 0x2a,
-            0x3d, 0xfc, 0x15, 0x46, 0xca, 0xd0, 0x04, 0x37, 0x8d, 0xaf, 0x05, 0x92, 0xd7, 0xfb,
-            0xb4, 0x1e, 0x66, 0x02,
-        ])
-        .unwrap()
+        Self::ROOT_OF_UNITY
     }
 }
 
@@ -472,6 +664,14 @@ impl Add<&Scalar> for Scalar {
     }
 }
 
+impl Add<Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: Scalar) -> Scalar {
+        Scalar::add(self, &other)
+    }
+}
+
 impl AddAssign<Scalar> for Scalar {
     fn add_assign(&mut self, rhs: Scalar) {
         *self = Scalar::add(self, &rhs);
@@ -508,6 +708,14 @@ impl Sub<&Scalar> for Scalar {
     }
 }
 
+impl Sub<Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn sub(self, other: Scalar) -> Scalar {
+        Scalar::sub(self, &other)
+    }
+}
+
 impl SubAssign<Scalar> for Scalar {
     fn sub_assign(&mut self, rhs: Scalar) {
         *self = Scalar::sub(self, &rhs);
@@ -544,6 +752,14 @@ impl Mul<&Scalar> for Scalar {
     }
 }
 
+impl Mul<Scalar> for &Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: Scalar) -> Scalar {
+        Scalar::mul(self, &other)
+    }
+}
+
 impl MulAssign<Scalar> for Scalar {
     fn mul_assign(&mut self, rhs: Scalar) {
         *self = Scalar::mul(self, &rhs);
@@ -560,15 +776,15 @@ impl Neg for Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
-        Scalar::zero() - self
+        Scalar::neg(&self)
     }
 }
 
-impl<'a> Neg for &'a Scalar {
+impl Neg for &Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
-        Scalar::zero() - self
+        Scalar::neg(self)
     }
 }
 
@@ -625,9 +841,13 @@ impl<'de> Deserialize<'de> for Scalar {
 
 #[cfg(test)]
 mod tests {
-    use super::Scalar;
+    use super::{Scalar, MODULUS};
     use crate::{FieldBytes, SecretKey};
-    use elliptic_curve::group::ff::{Field, PrimeField};
+    use elliptic_curve::{
+        bigint::{Encoding, U256},
+        group::ff::{Field, PrimeField},
+    };
+    use hex_literal::hex;
 
     #[test]
     fn from_to_bytes_roundtrip() {
@@ -639,38 +859,274 @@ mod tests {
         assert_eq!(bytes, scalar.to_bytes());
     }
 
+    #[test]
+    fn from_digest_truncated() {
+        // SHA-512("sample"): only the leftmost 256 bits are used
+        let digest = hex!(
+            "39a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f4
+             9a739468ab8049bf18eef820cdb1ad6c9015f838556bc7fad4138b23fdf986c7"
+        );
+        assert_eq!(
+            Scalar::from_digest_truncated(&digest).to_bytes(),
+            hex!("39a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f4").into()
+        );
+
+        // SHA-224("sample"): shorter digests are zero-extended on the left
+        let digest = hex!("9003e374bc726550c2c289447fd0533160f875709386dfa377bfd41c");
+        assert_eq!(
+            Scalar::from_digest_truncated(&digest).to_bytes(),
+            hex!("000000009003e374bc726550c2c289447fd0533160f875709386dfa377bfd41c").into()
+        );
+
+        // Truncated values which exceed `n` are reduced
+        assert_eq!(
+            Scalar::from_digest_truncated(&[0xff; 64]).to_bytes(),
+            hex!("00000000ffffffff00000000000000004319055258e8617b0c46353d039cdaae").into()
+        );
+    }
+
+    #[test]
+    fn uint_round_trip() {
+        let scalar = -Scalar::from(42u64);
+        assert_eq!(scalar.to_uint(), MODULUS.wrapping_sub(&U256::from(42u64)));
+        assert_eq!(Scalar::from_uint_unchecked(scalar.to_uint()), scalar);
+    }
+
+    #[test]
+    fn montgomery_limbs_round_trip() {
+        use elliptic_curve::rand_core::OsRng;
+
+        assert_eq!(Scalar::ZERO.to_montgomery_limbs(), [0; 4]);
+        assert_eq!(
+            Scalar::ONE.to_montgomery_limbs(),
+            [
+                0x0c46_353d_039c_daaf,
+                0x4319_0552_58e8_617b,
+                0x0000_0000_0000_0000,
+                0x0000_0000_ffff_ffff,
+            ]
+        );
+
+        for scalar in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::TWO_INV,
+            Scalar::random(&mut OsRng),
+        ] {
+            let limbs = scalar.to_montgomery_limbs();
+            assert_eq!(Scalar::from_montgomery_limbs_unchecked(limbs), scalar);
+        }
+    }
+
+    #[test]
+    fn little_endian_bytes() {
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::from(42u64)] {
+            let mut be = scalar.to_bytes();
+            be.reverse();
+            assert_eq!(&be[..], &scalar.to_bytes_le()[..]);
+            assert_eq!(
+                Scalar::from_bytes_le(&scalar.to_bytes_le()).unwrap(),
+                scalar
+            );
+        }
+
+        // Non-canonical encodings are rejected
+        let n = MODULUS.to_le_bytes();
+        assert!(bool::from(Scalar::from_bytes_le(&n).is_none()));
+        assert!(bool::from(Scalar::from_bytes_le(&[0xff; 32]).is_none()));
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn biguint_round_trip() {
+        use num_bigint::BigUint;
+
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE, Scalar::from(42u64)] {
+            let uint = scalar.to_biguint();
+            assert_eq!(BigUint::from_bytes_be(&scalar.to_bytes()), uint);
+            assert_eq!(Scalar::try_from_biguint(&uint), Some(scalar));
+        }
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn biguint_out_of_range() {
+        use num_bigint::BigUint;
+
+        let n = (-Scalar::ONE).to_biguint() + 1u8;
+        assert_eq!(Scalar::try_from_biguint(&(&n - 1u8)), Some(-Scalar::ONE));
+        assert_eq!(Scalar::try_from_biguint(&n), None);
+        assert_eq!(Scalar::try_from_biguint(&(BigUint::from(1u8) << 256)), None);
+    }
+
     /// Basic tests that multiplication works.
     #[test]
     fn multiply() {
         let one = Scalar::one();
-        let two = one + &one;
-        let three = two + &one;
-        let six = three + &three;
-        assert_eq!(six, two * &three);
+        let two = one + one;
+        let three = two + one;
+        let six = three + three;
+        assert_eq!(six, two * three);
 
         let minus_two = -two;
         let minus_three = -three;
         assert_eq!(two, -minus_two);
 
-        assert_eq!(minus_three * &minus_two, minus_two * &minus_three);
-        assert_eq!(six, minus_two * &minus_three);
+        assert_eq!(minus_three * minus_two, minus_two * minus_three);
+        assert_eq!(six, minus_two * minus_three);
     }
 
     /// Basic tests that scalar inversion works.
     #[test]
     fn invert() {
         let one = Scalar::one();
-        let three = one + &one + &one;
+        let three = one + one + one;
         let inv_three = three.invert().unwrap();
         // println!("1/3 = {:x?}", &inv_three);
-        assert_eq!(three * &inv_three, one);
+        assert_eq!(three * inv_three, one);
 
         let minus_three = -three;
         // println!("-3 = {:x?}", &minus_three);
         let inv_minus_three = minus_three.invert().unwrap();
         assert_eq!(inv_minus_three, -inv_three);
         // println!("-1/3 = {:x?}", &inv_minus_three);
-        assert_eq!(three * &inv_minus_three, -one);
+        assert_eq!(three * inv_minus_three, -one);
+    }
+
+    /// Field axioms, through every form of the operator traits.
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn algebraic_identities() {
+        use elliptic_curve::rand_core::OsRng;
+
+        let a = Scalar::random(&mut OsRng);
+        let b = Scalar::random(&mut OsRng);
+        let c = -Scalar::from(7u64);
+
+        // Owned and borrowed operands agree with the inherent methods
+        assert_eq!(a + b, a.add(&b));
+        assert_eq!(&a + &b, a.add(&b));
+        assert_eq!(a + &b, &a + b);
+        assert_eq!(a - b, a.sub(&b));
+        assert_eq!(&a - &b, a - &b);
+        assert_eq!(&a - b, a.sub(&b));
+        assert_eq!(a * b, a.mul(&b));
+        assert_eq!(&a * &b, a * &b);
+        assert_eq!(&a * b, a.mul(&b));
+        assert_eq!(-a, a.neg());
+        assert_eq!(-&a, a.neg());
+
+        let mut x = a;
+        x += b;
+        x += &c;
+        x -= a;
+        x -= &b;
+        assert_eq!(x, c);
+        x *= a;
+        x *= &b;
+        assert_eq!(x, a * b * c);
+
+        // Commutativity, associativity and distributivity
+        assert_eq!(a + b, b + a);
+        assert_eq!(a * b, b * a);
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * (b + c), a * b + a * c);
+
+        // Identities and inverses
+        assert_eq!(a + Scalar::ZERO, a);
+        assert_eq!(a * Scalar::ONE, a);
+        assert_eq!(a * Scalar::ZERO, Scalar::ZERO);
+        assert_eq!(a + -a, Scalar::ZERO);
+        assert_eq!(a - b, a + -b);
+        assert_eq!(-(-a), a);
+        assert_eq!(Scalar::ZERO.neg(), Scalar::ZERO);
+        assert_eq!(-Scalar::ONE * -Scalar::ONE, Scalar::ONE);
+    }
+
+    /// `PrimeField` invariants and the inherent constants backing them.
+    #[test]
+    fn prime_field_constants() {
+        use elliptic_curve::bigint::Integer;
+
+        assert_eq!(Scalar::from(2u64) * Scalar::TWO_INV, Scalar::ONE);
+        assert_eq!(
+            Scalar::multiplicative_generator(),
+            Scalar::MULTIPLICATIVE_GENERATOR
+        );
+        assert_eq!(Scalar::root_of_unity(), Scalar::ROOT_OF_UNITY);
+        assert_eq!(<Scalar as PrimeField>::S, Scalar::S);
+
+        // `n - 1 = 2^S * t` with `t` odd
+        let t = MODULUS.wrapping_sub(&U256::ONE) >> (Scalar::S as usize);
+        assert!(bool::from(t.is_odd()));
+        assert_eq!(t << (Scalar::S as usize), MODULUS.wrapping_sub(&U256::ONE));
+
+        // `ROOT_OF_UNITY = g^t` has order exactly `2^S`
+        assert_eq!(
+            Scalar::MULTIPLICATIVE_GENERATOR.pow_vartime(t.as_words()),
+            Scalar::ROOT_OF_UNITY
+        );
+        let mut root = Scalar::ROOT_OF_UNITY;
+        for _ in 0..Scalar::S - 1 {
+            root = root.square();
+            assert_ne!(root, Scalar::ONE);
+        }
+        assert_eq!(root.square(), Scalar::ONE);
+        assert_eq!(
+            Scalar::ROOT_OF_UNITY * Scalar::ROOT_OF_UNITY_INV,
+            Scalar::ONE
+        );
+
+        // `DELTA = g^(2^S)`, and `g` is a quadratic non-residue
+        assert_eq!(
+            Scalar::MULTIPLICATIVE_GENERATOR.pow_vartime([1 << Scalar::S]),
+            Scalar::DELTA
+        );
+        assert!(bool::from(
+            Scalar::MULTIPLICATIVE_GENERATOR.sqrt().is_none()
+        ));
+
+        // Representation round trip and parity
+        let x = Scalar::from(5u64);
+        assert_eq!(Scalar::from_repr(x.to_repr()).unwrap(), x);
+        assert!(bool::from(x.is_odd()));
+        assert!(bool::from(
+            Scalar::from_repr(MODULUS.to_be_bytes().into()).is_none()
+        ));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert() {
+        use elliptic_curve::rand_core::OsRng;
+
+        let scalars = [0u8; 8].map(|_| Scalar::random(&mut OsRng));
+        let mut inverses = scalars;
+        let product_inv = Scalar::batch_invert(&mut inverses).unwrap();
+
+        for (scalar, inverse) in scalars.iter().zip(&inverses) {
+            assert_eq!(*inverse, scalar.invert().unwrap());
+        }
+        assert_eq!(
+            product_inv,
+            scalars
+                .iter()
+                .fold(Scalar::ONE, |acc, s| acc * s)
+                .invert()
+                .unwrap()
+        );
+
+        // Any zero fails the whole batch and leaves it untouched
+        let mut with_zero = scalars;
+        with_zero[3] = Scalar::ZERO;
+        assert!(bool::from(Scalar::batch_invert(&mut with_zero).is_none()));
+        assert_eq!(with_zero[..3], scalars[..3]);
+        assert_eq!(with_zero[4..], scalars[4..]);
+
+        // The empty product is one
+        assert_eq!(Scalar::batch_invert(&mut []).unwrap(), Scalar::ONE);
     }
 
     /// Basic tests that sqrt works.
@@ -0,0 +1,433 @@
+//! C ABI for ECDSA/P-256 key generation, signing, verification and public
+//! key recovery.
+//!
+//! Every function takes its inputs and outputs as pointers to raw bytes
+//! along with their lengths, and returns [`P256_OK`] on success or one of the
+//! negative `P256_ERR_*` codes on failure. Null pointers and buffers of the
+//! wrong length are reported as errors; no function panics on any input.
+//!
+//! Buffers have the following fixed lengths:
+//!
+//! - secret keys: 32 bytes, big endian
+//! - public keys: 33 bytes, compressed SEC1 (verification additionally
+//!   accepts 65-byte uncompressed SEC1)
+//! - message hashes: 32 bytes
+//! - signatures: 64 bytes (`r || s`), or 65 bytes (`r || s || v`) for
+//!   recoverable signatures
+//!
+//! Output buffers are only written to on success.
+
+#![allow(unsafe_code)]
+
+use crate::{
+    ecdsa::{recoverable, verify_prehash, P256Error, SigningKey, VerifyingKey},
+    elliptic_curve::zeroize::Zeroize,
+    FieldBytes,
+};
+use core::ptr;
+use rand_core::{OsRng, RngCore};
+
+/// Success.
+pub const P256_OK: i32 = 0;
+
+/// A pointer argument was null.
+pub const P256_ERR_NULL_POINTER: i32 = -1;
+
+/// A buffer has the wrong length.
+pub const P256_ERR_INVALID_LENGTH: i32 = -2;
+
+/// Secret key is zero or not less than the group order.
+pub const P256_ERR_INVALID_SECRET_KEY: i32 = -3;
+
+/// Public key is malformed, the identity or not on the curve.
+pub const P256_ERR_INVALID_PUBLIC_KEY: i32 = -4;
+
+/// Signature is malformed, or no public key can be recovered from it.
+pub const P256_ERR_INVALID_SIGNATURE: i32 = -5;
+
+/// Signature is well-formed but does not verify.
+pub const P256_ERR_VERIFICATION_FAILED: i32 = -6;
+
+/// The operating system's random number generator failed.
+pub const P256_ERR_RNG: i32 = -7;
+
+/// Size of a secret key.
+const SECRET_KEY_SIZE: usize = 32;
+
+/// Size of a compressed public key.
+const PUBLIC_KEY_SIZE: usize = 33;
+
+/// Size of an uncompressed public key.
+const UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
+
+/// Size of a message hash.
+const HASH_SIZE: usize = 32;
+
+/// Size of a signature.
+const SIGNATURE_SIZE: usize = 64;
+
+/// Generate a random secret key along with its public key.
+///
+/// # Safety
+///
+/// `secret_key_out` and `public_key_out` must either be null or valid for
+/// writes of `secret_key_len` and `public_key_len` bytes respectively.
+#[no_mangle]
+pub unsafe extern "C" fn p256_keygen(
+    secret_key_out: *mut u8,
+    secret_key_len: usize,
+    public_key_out: *mut u8,
+    public_key_len: usize,
+) -> i32 {
+    if let Err(code) = check_output(secret_key_out, secret_key_len, SECRET_KEY_SIZE)
+        .and_then(|_| check_output(public_key_out, public_key_len, PUBLIC_KEY_SIZE))
+    {
+        return code;
+    }
+
+    let mut bytes = [0u8; SECRET_KEY_SIZE];
+
+    let signing_key = loop {
+        if OsRng.try_fill_bytes(&mut bytes).is_err() {
+            bytes.zeroize();
+            return P256_ERR_RNG;
+        }
+
+        if let Ok(signing_key) = SigningKey::from_bytes(&bytes) {
+            break signing_key;
+        }
+    };
+
+    let public_key = VerifyingKey::from(&signing_key).to_bytes();
+    write_output(&bytes, secret_key_out);
+    write_output(public_key.as_ref(), public_key_out);
+    bytes.zeroize();
+    P256_OK
+}
+
+/// Sign a 32-byte message hash, producing a 65-byte recoverable signature
+/// with an RFC6979 nonce.
+///
+/// # Safety
+///
+/// `secret_key` and `msg_hash` must either be null or valid for reads of
+/// `secret_key_len` and `msg_hash_len` bytes respectively, and `sig_out`
+/// must either be null or valid for writes of `sig_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn p256_sign_recoverable(
+    secret_key: *const u8,
+    secret_key_len: usize,
+    msg_hash: *const u8,
+    msg_hash_len: usize,
+    sig_out: *mut u8,
+    sig_len: usize,
+) -> i32 {
+    let result = (|| {
+        let mut bytes = read_input::<SECRET_KEY_SIZE>(secret_key, secret_key_len)?;
+        let msg_hash = read_input::<HASH_SIZE>(msg_hash, msg_hash_len)?;
+        check_output(sig_out, sig_len, recoverable::SIZE)?;
+
+        let signing_key = SigningKey::from_bytes(&bytes);
+        bytes.zeroize();
+
+        let signature = signing_key
+            .map_err(|_| P256_ERR_INVALID_SECRET_KEY)?
+            .sign_prehash_recoverable(FieldBytes::from(msg_hash))
+            .map_err(|_| P256_ERR_INVALID_SECRET_KEY)?;
+
+        write_output(signature.as_ref(), sig_out);
+        Ok(())
+    })();
+
+    status(result)
+}
+
+/// Verify a 64-byte signature over a 32-byte message hash under a compressed
+/// or uncompressed SEC1-encoded public key.
+///
+/// # Safety
+///
+/// `public_key`, `msg_hash` and `sig` must either be null or valid for reads
+/// of `public_key_len`, `msg_hash_len` and `sig_len` bytes respectively.
+#[no_mangle]
+pub unsafe extern "C" fn p256_verify(
+    public_key: *const u8,
+    public_key_len: usize,
+    msg_hash: *const u8,
+    msg_hash_len: usize,
+    sig: *const u8,
+    sig_len: usize,
+) -> i32 {
+    let result = (|| {
+        let mut public_key_buf = [0u8; UNCOMPRESSED_PUBLIC_KEY_SIZE];
+        let public_key = match public_key_len {
+            PUBLIC_KEY_SIZE => {
+                public_key_buf[..PUBLIC_KEY_SIZE]
+                    .copy_from_slice(&read_input::<PUBLIC_KEY_SIZE>(public_key, public_key_len)?);
+                &public_key_buf[..PUBLIC_KEY_SIZE]
+            }
+            _ => {
+                public_key_buf = read_input(public_key, public_key_len)?;
+                &public_key_buf[..]
+            }
+        };
+
+        let msg_hash = read_input::<HASH_SIZE>(msg_hash, msg_hash_len)?;
+        let sig = read_input::<SIGNATURE_SIZE>(sig, sig_len)?;
+
+        verify_prehash(public_key, &msg_hash, &sig).map_err(|err| match err {
+            P256Error::InvalidEncoding
+            | P256Error::Identity
+            | P256Error::PointNotOnCurve
+            | P256Error::CoordinateOutOfRange => P256_ERR_INVALID_PUBLIC_KEY,
+            P256Error::VerificationFailed => P256_ERR_VERIFICATION_FAILED,
+            _ => P256_ERR_INVALID_SIGNATURE,
+        })
+    })();
+
+    status(result)
+}
+
+/// Recover the compressed SEC1-encoded public key which produced a 65-byte
+/// recoverable signature over a 32-byte message hash.
+///
+/// # Safety
+///
+/// `msg_hash` and `sig` must either be null or valid for reads of
+/// `msg_hash_len` and `sig_len` bytes respectively, and `public_key_out`
+/// must either be null or valid for writes of `public_key_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn p256_recover(
+    msg_hash: *const u8,
+    msg_hash_len: usize,
+    sig: *const u8,
+    sig_len: usize,
+    public_key_out: *mut u8,
+    public_key_len: usize,
+) -> i32 {
+    let result = (|| {
+        let msg_hash = read_input::<HASH_SIZE>(msg_hash, msg_hash_len)?;
+        let sig = read_input::<{ recoverable::SIZE }>(sig, sig_len)?;
+        check_output(public_key_out, public_key_len, PUBLIC_KEY_SIZE)?;
+
+        let public_key = recoverable::Signature::try_from(&sig[..])
+            .and_then(|sig| sig.recover_verifying_key_from_digest_bytes(&msg_hash.into()))
+            .map_err(|_| P256_ERR_INVALID_SIGNATURE)?
+            .to_bytes();
+
+        write_output(public_key.as_ref(), public_key_out);
+        Ok(())
+    })();
+
+    status(result)
+}
+
+/// Copy `len` bytes from `ptr`, which must be exactly `N` bytes long.
+///
+/// # Safety
+///
+/// `ptr` must either be null or valid for reads of `len` bytes.
+unsafe fn read_input<const N: usize>(ptr: *const u8, len: usize) -> Result<[u8; N], i32> {
+    if ptr.is_null() {
+        return Err(P256_ERR_NULL_POINTER);
+    }
+
+    if len != N {
+        return Err(P256_ERR_INVALID_LENGTH);
+    }
+
+    let mut bytes = [0u8; N];
+    ptr::copy_nonoverlapping(ptr, bytes.as_mut_ptr(), N);
+    Ok(bytes)
+}
+
+/// Ensure an output buffer is non-null and exactly `size` bytes long.
+fn check_output(ptr: *mut u8, len: usize, size: usize) -> Result<(), i32> {
+    if ptr.is_null() {
+        Err(P256_ERR_NULL_POINTER)
+    } else if len != size {
+        Err(P256_ERR_INVALID_LENGTH)
+    } else {
+        Ok(())
+    }
+}
+
+/// Copy `bytes` to an output buffer previously validated by [`check_output`].
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of `bytes.len()` bytes.
+unsafe fn write_output(bytes: &[u8], ptr: *mut u8) {
+    ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+}
+
+/// Convert the result of a function body into a return code.
+fn status(result: Result<(), i32>) -> i32 {
+    match result {
+        Ok(()) => P256_OK,
+        Err(code) => code,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic_curve::sec1::ToEncodedPoint;
+    use hex_literal::hex;
+
+    const MSG_HASH: [u8; 32] =
+        hex!("50d858e0985ecc7f60418aaf0cc5ab587f42c2570a884095a9e8ccacd0f6545c");
+
+    #[test]
+    fn round_trip() {
+        unsafe {
+            let mut sk = [0u8; 32];
+            let mut pk = [0u8; 33];
+            assert_eq!(
+                p256_keygen(sk.as_mut_ptr(), 32, pk.as_mut_ptr(), 33),
+                P256_OK
+            );
+
+            let mut sig = [0u8; 65];
+            assert_eq!(
+                p256_sign_recoverable(sk.as_ptr(), 32, MSG_HASH.as_ptr(), 32, sig.as_mut_ptr(), 65),
+                P256_OK
+            );
+
+            assert_eq!(
+                p256_verify(pk.as_ptr(), 33, MSG_HASH.as_ptr(), 32, sig.as_ptr(), 64),
+                P256_OK
+            );
+
+            let mut recovered = [0u8; 33];
+            assert_eq!(
+                p256_recover(
+                    MSG_HASH.as_ptr(),
+                    32,
+                    sig.as_ptr(),
+                    65,
+                    recovered.as_mut_ptr(),
+                    33
+                ),
+                P256_OK
+            );
+            assert_eq!(recovered, pk);
+
+            // Uncompressed public keys are accepted for verification
+            let uncompressed = VerifyingKey::from_sec1_bytes(&pk)
+                .unwrap()
+                .to_encoded_point(false);
+            assert_eq!(
+                p256_verify(
+                    uncompressed.as_bytes().as_ptr(),
+                    65,
+                    MSG_HASH.as_ptr(),
+                    32,
+                    sig.as_ptr(),
+                    64
+                ),
+                P256_OK
+            );
+
+            let other_hash = [0u8; 32];
+            assert_eq!(
+                p256_verify(pk.as_ptr(), 33, other_hash.as_ptr(), 32, sig.as_ptr(), 64),
+                P256_ERR_VERIFICATION_FAILED
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        unsafe {
+            let mut sk = [0u8; 32];
+            let mut pk = [0u8; 33];
+            let mut sig = [0u8; 65];
+
+            assert_eq!(
+                p256_keygen(ptr::null_mut(), 32, pk.as_mut_ptr(), 33),
+                P256_ERR_NULL_POINTER
+            );
+            assert_eq!(
+                p256_keygen(sk.as_mut_ptr(), 32, pk.as_mut_ptr(), 65),
+                P256_ERR_INVALID_LENGTH
+            );
+            assert_eq!(pk, [0u8; 33]);
+
+            assert_eq!(
+                p256_sign_recoverable(sk.as_ptr(), 32, MSG_HASH.as_ptr(), 32, sig.as_mut_ptr(), 65),
+                P256_ERR_INVALID_SECRET_KEY
+            );
+            assert_eq!(
+                p256_sign_recoverable(sk.as_ptr(), 32, ptr::null(), 32, sig.as_mut_ptr(), 65),
+                P256_ERR_NULL_POINTER
+            );
+            assert_eq!(
+                p256_sign_recoverable(sk.as_ptr(), 31, MSG_HASH.as_ptr(), 32, sig.as_mut_ptr(), 65),
+                P256_ERR_INVALID_LENGTH
+            );
+
+            assert_eq!(
+                p256_keygen(sk.as_mut_ptr(), 32, pk.as_mut_ptr(), 33),
+                P256_OK
+            );
+            assert_eq!(
+                p256_sign_recoverable(sk.as_ptr(), 32, MSG_HASH.as_ptr(), 32, sig.as_mut_ptr(), 65),
+                P256_OK
+            );
+
+            assert_eq!(
+                p256_verify(pk.as_ptr(), 32, MSG_HASH.as_ptr(), 32, sig.as_ptr(), 64),
+                P256_ERR_INVALID_LENGTH
+            );
+            assert_eq!(
+                p256_verify(pk.as_ptr(), 33, MSG_HASH.as_ptr(), 32, sig.as_ptr(), 65),
+                P256_ERR_INVALID_LENGTH
+            );
+            assert_eq!(
+                p256_verify(pk.as_ptr(), 33, MSG_HASH.as_ptr(), 32, ptr::null(), 64),
+                P256_ERR_NULL_POINTER
+            );
+            let bad_pk = [0xff; 33];
+            assert_eq!(
+                p256_verify(bad_pk.as_ptr(), 33, MSG_HASH.as_ptr(), 32, sig.as_ptr(), 64),
+                P256_ERR_INVALID_PUBLIC_KEY
+            );
+            let bad_sig = [0u8; 64];
+            assert_eq!(
+                p256_verify(pk.as_ptr(), 33, MSG_HASH.as_ptr(), 32, bad_sig.as_ptr(), 64),
+                P256_ERR_INVALID_SIGNATURE
+            );
+
+            let mut recovered = [0u8; 33];
+            assert_eq!(
+                p256_recover(
+                    MSG_HASH.as_ptr(),
+                    32,
+                    sig.as_ptr(),
+                    64,
+                    recovered.as_mut_ptr(),
+                    33
+                ),
+                P256_ERR_INVALID_LENGTH
+            );
+            assert_eq!(
+                p256_recover(MSG_HASH.as_ptr(), 32, sig.as_ptr(), 65, ptr::null_mut(), 33),
+                P256_ERR_NULL_POINTER
+            );
+            sig[64] = 2;
+            assert_eq!(
+                p256_recover(
+                    MSG_HASH.as_ptr(),
+                    32,
+                    sig.as_ptr(),
+                    65,
+                    recovered.as_mut_ptr(),
+                    33
+                ),
+                P256_ERR_INVALID_SIGNATURE
+            );
+            assert_eq!(recovered, [0u8; 33]);
+        }
+    }
+}
@@ -38,10 +38,60 @@
 
 pub use elliptic_curve::ecdh::diffie_hellman;
 
-use crate::NistP256;
+use crate::{NistP256, Scalar, U256};
+use elliptic_curve::ops::Reduce;
 
 /// NIST P-256 Ephemeral Diffie-Hellman Secret.
 pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<NistP256>;
 
 /// Shared secret value computed via ECDH key agreement.
 pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<NistP256>;
+
+/// Interpret the `x`-coordinate of a shared secret as a big endian integer
+/// and reduce it modulo the group order `n`.
+///
+/// ⚠️ **Warning**: raw Diffie-Hellman output is not uniformly random and
+/// should almost always be passed through a KDF (e.g. with
+/// [`SharedSecret::extract`]) instead. This exists for interoperability
+/// with legacy protocols which use it as a scalar directly.
+///
+/// The result is zero if the `x`-coordinate is a multiple of `n`, which
+/// happens with negligible probability.
+pub fn shared_secret_to_scalar(shared_secret: &SharedSecret) -> Scalar {
+    <Scalar as Reduce<U256>>::from_be_bytes_reduced(*shared_secret.raw_secret_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diffie_hellman, shared_secret_to_scalar, SharedSecret};
+    use crate::{FieldBytes, NistP256, NonZeroScalar, PublicKey, Scalar, U256};
+    use elliptic_curve::{bigint::ArrayEncoding, rand_core::OsRng, Curve};
+
+    #[test]
+    fn to_scalar_reduces() {
+        // x < n is taken as-is
+        let x = U256::from_u64(5);
+        let shared_secret = SharedSecret::from(x.to_be_byte_array());
+        assert_eq!(shared_secret_to_scalar(&shared_secret), Scalar::from(5u64));
+
+        // n <= x < p wraps around
+        let x = NistP256::ORDER.wrapping_add(&U256::from_u64(5));
+        let shared_secret = SharedSecret::from(x.to_be_byte_array());
+        assert_eq!(shared_secret_to_scalar(&shared_secret), Scalar::from(5u64));
+
+        let shared_secret = SharedSecret::from(NistP256::ORDER.to_be_byte_array());
+        assert_eq!(shared_secret_to_scalar(&shared_secret), Scalar::ZERO);
+    }
+
+    #[test]
+    fn to_scalar_matches_ecdh() {
+        let secret = NonZeroScalar::random(&mut OsRng);
+        let public = PublicKey::from_secret_scalar(&NonZeroScalar::random(&mut OsRng));
+        let shared_secret = diffie_hellman(secret, public.as_affine());
+
+        let scalar = shared_secret_to_scalar(&shared_secret);
+        let x = FieldBytes::clone_from_slice(shared_secret.raw_secret_bytes());
+        let expected = U256::from_be_byte_array(x).reduce(&NistP256::ORDER);
+        assert_eq!(scalar.to_bytes(), expected.unwrap().to_be_byte_array());
+    }
+}
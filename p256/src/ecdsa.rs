@@ -39,20 +39,51 @@
 //! # }
 //! ```
 
+pub mod der;
 pub mod recoverable;
 
+#[cfg(feature = "keccak256")]
+#[cfg_attr(docsrs, doc(cfg(feature = "keccak256")))]
+pub mod recoverable_keccak;
+
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub mod nonce;
+
+#[cfg(feature = "test-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod test_utils;
+
+mod error;
+
 #[cfg(feature = "ecdsa")]
 mod sign;
 #[cfg(feature = "ecdsa")]
 mod verify;
 
+pub use self::error::P256Error;
 pub use ecdsa_core::signature::{self, Error};
 
 #[cfg(feature = "digest")]
 pub use ecdsa_core::signature::digest;
 
 #[cfg(feature = "ecdsa")]
-pub use self::{sign::SigningKey, verify::VerifyingKey};
+pub use self::{
+    sign::{Prehashed, SigningKey, SigningKeyWithCache},
+    verify::{verify_prehash, PrecomputedVerifyingKey, VerificationPolicy, VerifyingKey},
+};
+
+#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+pub use self::verify::verify_multisig;
+
+#[cfg(all(feature = "ecdsa", feature = "sha256"))]
+pub use self::verify::{verify_any, PrefixVerifier};
+
+#[cfg(all(feature = "ecdsa", feature = "pkcs8"))]
+pub use self::verify::SubjectPublicKeyInfoOwned;
+
+#[cfg(all(feature = "ecdsa", feature = "std"))]
+pub use self::verify::verify_reader;
 
 use super::NistP256;
 
@@ -68,16 +99,35 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP256 {
     type Digest = sha2::Sha256;
 }
 
+/// Get the low-`s` form of a signature, which is the signature itself if
+/// its `s` is already low.
+///
+/// Unlike [`Signature::normalize_s`], this always returns a signature, so it
+/// can be used to map both malleable forms to the same value, e.g. for
+/// deduplication. See [`recoverable::Signature::canonicalize`] for
+/// recoverable signatures.
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub fn canonicalize(signature: &Signature) -> Signature {
+    signature.normalize_s().unwrap_or(*signature)
+}
+
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
     use crate::{
-        ecdsa::{recoverable, sign::SigningKey, signature::Signer, Signature},
+        ecdsa::{
+            recoverable,
+            sign::SigningKey,
+            signature::{DigestSigner, Signer},
+            Signature,
+        },
         test_vectors::ecdsa::ECDSA_TEST_VECTORS,
         BlindedScalar, Scalar,
     };
     use ecdsa_core::hazmat::SignPrimitive;
     use elliptic_curve::{generic_array::GenericArray, group::ff::PrimeField, rand_core::OsRng};
     use hex_literal::hex;
+    use sha2::{Digest, Sha256};
 
     // Test vector from RFC 6979 Appendix 2.5 (NIST P-256 + SHA-256)
     // <https://tools.ietf.org/html/rfc6979#appendix-A.2.5>
@@ -86,13 +136,19 @@ mod tests {
         let x = &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
         let signer = SigningKey::from_bytes(x).unwrap();
         let signature: Signature = signer.sign(b"sample");
-        assert_eq!(
-            signature.as_ref(),
+        let expected = Signature::try_from(
             &hex!(
                 "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716
                      f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
-            )[..]
-        );
+            )[..],
+        )
+        .unwrap();
+
+        // The RFC's `s` is high
+        #[cfg(feature = "always-low-s")]
+        let expected = super::canonicalize(&expected);
+
+        assert_eq!(signature, expected);
         let signature: recoverable::Signature = signer.sign(b"test");
         assert_eq!(
             signature.as_ref(),
@@ -103,6 +159,188 @@ mod tests {
         );
     }
 
+    #[test]
+    fn canonicalize() {
+        use super::canonicalize;
+        use crate::ecdsa::{signature::Verifier, VerifyingKey};
+        use elliptic_curve::IsHigh;
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let signature: Signature = signing_key.sign(b"canonicalize");
+        let (r, s) = signature.split_scalars();
+        let twin = Signature::from_scalars(r, -*s).unwrap();
+
+        // Signing doesn't normalize `s`, so sort out which one is low
+        let (low_s, high_s) = if bool::from(s.as_ref().is_high()) {
+            (twin, signature)
+        } else {
+            (signature, twin)
+        };
+
+        assert_eq!(canonicalize(&low_s), low_s);
+        assert_eq!(canonicalize(&high_s), low_s);
+        assert_eq!(canonicalize(&canonicalize(&high_s)), canonicalize(&high_s));
+        assert!(verifying_key.verify(b"canonicalize", &high_s).is_ok());
+
+        let signature: recoverable::Signature = signing_key.sign(b"canonicalize");
+        let twin = recoverable::Signature::new(
+            &Signature::from_scalars(signature.r(), -*signature.s()).unwrap(),
+            recoverable::Id::new(u8::from(signature.recovery_id()) ^ 1).unwrap(),
+        )
+        .unwrap();
+
+        let (low_s, high_s) = if bool::from(signature.s().as_ref().is_high()) {
+            (twin, signature)
+        } else {
+            (signature, twin)
+        };
+
+        assert_eq!(low_s.canonicalize(), low_s);
+        assert_eq!(high_s.canonicalize(), low_s);
+        assert_eq!(high_s.canonicalize().canonicalize(), high_s.canonicalize());
+        assert_eq!(
+            high_s.recover_verifying_key(b"canonicalize").unwrap(),
+            verifying_key
+        );
+    }
+
+    #[test]
+    fn additive_shares() {
+        use crate::ecdsa::{signature::Verifier, VerifyingKey};
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let (a, b) = signing_key.split_additive(&mut OsRng);
+        assert_ne!(a.to_bytes(), signing_key.to_bytes());
+
+        let reconstructed = SigningKey::from_additive_shares(&[a, b]).unwrap();
+        assert_eq!(reconstructed.to_bytes(), signing_key.to_bytes());
+
+        let signature: Signature = reconstructed.sign(b"additive shares");
+        assert!(VerifyingKey::from(&signing_key)
+            .verify(b"additive shares", &signature)
+            .is_ok());
+
+        // Splitting one share again yields a valid 3-party split
+        let (b1, b2) = SigningKey::from_additive_shares(&[b])
+            .unwrap()
+            .split_additive(&mut OsRng);
+        let reconstructed = SigningKey::from_additive_shares(&[a, b1, b2]).unwrap();
+        assert_eq!(reconstructed.to_bytes(), signing_key.to_bytes());
+
+        assert!(SigningKey::from_additive_shares(&[]).is_err());
+        assert!(SigningKey::from_additive_shares(&[a, -a]).is_err());
+    }
+
+    #[test]
+    fn sign_recoverable_canonical() {
+        let x = &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let verifying_key = signer.verifying_key();
+        let mut normalized = false;
+
+        for i in 0u8..16 {
+            let msg = [i];
+            let raw: recoverable::Signature = signer.sign(&msg);
+            let signature = signer.sign_recoverable_canonical(&msg);
+
+            normalized |= Signature::from(raw).normalize_s().is_some();
+            assert!(Signature::from(signature).normalize_s().is_none());
+            assert_eq!(
+                signature.recover_verifying_key(&msg).unwrap(),
+                verifying_key
+            );
+        }
+
+        // Make sure the high-`s` path was actually exercised
+        #[cfg(not(feature = "always-low-s"))]
+        assert!(normalized);
+        #[cfg(feature = "always-low-s")]
+        assert!(!normalized);
+    }
+
+    #[test]
+    fn sign_digest_normalized() {
+        let x = &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let mut normalized = 0;
+
+        for i in 0u8..64 {
+            let digest = Sha256::new_with_prefix([i]);
+            let raw: Signature = signer.sign_digest(digest.clone());
+            let (signature, flag) = signer.sign_digest_normalized(digest);
+
+            assert!(signature.normalize_s().is_none());
+            assert_eq!(flag, raw.normalize_s().is_some());
+            assert_eq!(signature, raw.normalize_s().unwrap_or(raw));
+            normalized += usize::from(flag);
+        }
+
+        // High-`s` signatures should occur roughly half of the time
+        #[cfg(not(feature = "always-low-s"))]
+        assert!((16..=48).contains(&normalized));
+        #[cfg(feature = "always-low-s")]
+        assert_eq!(normalized, 0);
+    }
+
+    #[test]
+    fn sign_digest_rfc6979() {
+        use crate::ecdsa::{signature::DigestVerifier, VerifyingKey};
+        use sha2::Sha512;
+
+        let x = &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let verifier = VerifyingKey::from(&signer);
+        let digest = Sha256::new_with_prefix(b"sample");
+
+        let expected: Signature = signer.sign_digest(digest.clone());
+        let signature = signer.sign_digest_rfc6979::<Sha256, _>(digest.clone());
+        assert_eq!(signature, expected);
+
+        let signature = signer.sign_digest_rfc6979::<Sha512, _>(digest.clone());
+        assert_ne!(signature, expected);
+        assert!(verifier.verify_digest(digest, &signature).is_ok());
+
+        // RFC6979 Appendix 2.5 (NIST P-256 + SHA-512), where the SHA-512
+        // digest of "sample" is truncated to its leftmost 256 bits
+        let z = hex!("39a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f4");
+        let signature = signer.sign_prehash_rfc6979::<Sha512>(z.into());
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "8496a60b5e9b47c825488827e0495b0e3fa109ec4568fd3f8d1097678eb97f00
+                 2362ab1adbe2b8adf9cb9edab740ea6049c028114f2460f96554f61fae3302fe"
+            )[..]
+        );
+    }
+
+    #[cfg(feature = "dangerous-test-only")]
+    #[test]
+    fn sign_digest_exposing_nonce() {
+        use crate::ProjectivePoint;
+        use elliptic_curve::{bigint::U256, ops::Reduce, AffineXCoordinate};
+
+        let x = &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let signer = SigningKey::from_bytes(x).unwrap();
+        let digest = Sha256::new_with_prefix(b"sample");
+
+        let expected: Signature = signer.sign_digest(digest.clone());
+        let (signature, k) = signer.sign_digest_exposing_nonce(digest.clone()).unwrap();
+        assert_eq!(signature, expected);
+
+        // `r` must be the x-coordinate of `k * G`
+        let r = (ProjectivePoint::GENERATOR * k).to_affine().x();
+        assert_eq!(
+            *signature.r(),
+            <Scalar as Reduce<U256>>::from_be_bytes_reduced(r)
+        );
+
+        // The nonce reveals the signing key: d = (s * k - z) / r
+        let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(digest.finalize());
+        let d = (*signature.s() * k - z) * signature.r().invert().unwrap();
+        assert_eq!(d.to_bytes(), signer.to_bytes());
+    }
+
     #[test]
     fn scalar_blinding() {
         let vector = &ECDSA_TEST_VECTORS[0];
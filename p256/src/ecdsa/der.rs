@@ -0,0 +1,212 @@
+//! ASN.1 DER decoding of ECDSA/P-256 signatures.
+//!
+//! The [`Signature::from_der`] method provided by the `ecdsa` crate strictly
+//! enforces the DER rules. This module additionally provides a lenient
+//! [`from_der`] function which tolerates encodings produced by some signers
+//! in the wild.
+
+use super::{P256Error, Signature};
+use crate::FieldBytes;
+
+/// ASN.1 `SEQUENCE` tag.
+const SEQUENCE_TAG: u8 = 0x30;
+
+/// ASN.1 `INTEGER` tag.
+const INTEGER_TAG: u8 = 0x02;
+
+/// Parse an ASN.1 DER-encoded ECDSA/P-256 signature, tolerating a single
+/// unnecessary leading zero byte on the `r` and/or `s` integers.
+///
+/// Some signers (notably several Java-based ones) prefix `INTEGER` values
+/// with `0x00` even when the following byte does not have its high bit set.
+/// Strictly speaking this is not valid DER, however the encoded value is
+/// unambiguous so it is accepted here. All other encoding rules are still
+/// enforced: tags must match, lengths must be minimally encoded and there
+/// must be no trailing data.
+///
+/// Callers which want to detect relaxed encodings (e.g. to emit a warning)
+/// can check whether [`from_der_strict`] rejects the same input.
+pub fn from_der(bytes: &[u8]) -> Result<Signature, P256Error> {
+    let (r, s) = decode_signature(bytes, true)?;
+    Signature::from_scalars(r, s).map_err(|_| P256Error::InvalidEncoding)
+}
+
+/// Parse an ASN.1 DER-encoded ECDSA/P-256 signature, rejecting anything
+/// other than the unique DER encoding.
+///
+/// This accepts the same inputs as [`Signature::from_der`].
+pub fn from_der_strict(bytes: &[u8]) -> Result<Signature, P256Error> {
+    if !is_canonical(bytes) {
+        return Err(P256Error::InvalidEncoding);
+    }
+
+    from_der(bytes)
+}
+
+/// Is `bytes` the unique DER encoding of an ECDSA signature?
+///
+/// Checks the encoding only: a `SEQUENCE` of exactly two `INTEGER`s with
+/// minimally encoded lengths, no unnecessary leading zeros or negative
+/// values, integers no wider than a scalar, and no trailing data. Whether
+/// `r` and `s` are in range is left to the parser.
+///
+/// To check a [`DerSignature`][`super::DerSignature`], pass its
+/// `as_bytes()`.
+pub fn is_canonical(bytes: &[u8]) -> bool {
+    decode_signature(bytes, false).is_ok()
+}
+
+/// Decode the `r` and `s` integers of a DER-encoded signature, optionally
+/// tolerating a single unnecessary leading zero on each.
+fn decode_signature(bytes: &[u8], lenient: bool) -> Result<(FieldBytes, FieldBytes), P256Error> {
+    let (body, rest) = decode_tlv(SEQUENCE_TAG, bytes)?;
+
+    if !rest.is_empty() {
+        return Err(P256Error::InvalidEncoding);
+    }
+
+    let (r, body) = decode_integer(body, lenient)?;
+    let (s, body) = decode_integer(body, lenient)?;
+
+    if !body.is_empty() {
+        return Err(P256Error::InvalidEncoding);
+    }
+
+    Ok((r, s))
+}
+
+/// Decode a tag-length-value triple with the given tag, returning the value
+/// and the remaining input.
+///
+/// Only the short length form is supported: DER-encoded P-256 signatures are
+/// always shorter than 128 bytes.
+fn decode_tlv(tag: u8, bytes: &[u8]) -> Result<(&[u8], &[u8]), P256Error> {
+    match bytes {
+        [t, len, rest @ ..] if *t == tag && *len < 0x80 && rest.len() >= usize::from(*len) => {
+            Ok(rest.split_at(usize::from(*len)))
+        }
+        _ => Err(P256Error::InvalidEncoding),
+    }
+}
+
+/// Decode an unsigned ASN.1 `INTEGER` into a big endian [`FieldBytes`],
+/// returning it along with the remaining input.
+fn decode_integer(bytes: &[u8], lenient: bool) -> Result<(FieldBytes, &[u8]), P256Error> {
+    let (mut value, rest) = decode_tlv(INTEGER_TAG, bytes)?;
+
+    // Lenient: drop a single unnecessary leading zero
+    if let [0, byte, ..] = value {
+        if lenient && *byte < 0x80 {
+            value = &value[1..];
+        }
+    }
+
+    let value = match value {
+        [] => return Err(P256Error::InvalidEncoding),
+        [0] => value,
+        [0, byte, ..] if *byte < 0x80 => return Err(P256Error::InvalidEncoding),
+        [0, rest @ ..] => rest,
+        [byte, ..] if *byte >= 0x80 => return Err(P256Error::InvalidEncoding),
+        _ => value,
+    };
+
+    let mut field_bytes = FieldBytes::default();
+    let offset = field_bytes
+        .len()
+        .checked_sub(value.len())
+        .ok_or(P256Error::InvalidEncoding)?;
+
+    field_bytes[offset..].copy_from_slice(value);
+    Ok((field_bytes, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_der, from_der_strict, is_canonical};
+    use crate::ecdsa::P256Error;
+    use hex_literal::hex;
+
+    /// Wycheproof tcId: 304
+    const CANONICAL_SIGNATURE: [u8; 70] = hex!(
+        "30440220784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792022016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b"
+    );
+
+    /// Same signature as above with an unnecessary leading zero on `r`
+    const PADDED_R_SIGNATURE: [u8; 71] = hex!(
+        "3045022100784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792022016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b"
+    );
+
+    /// Same signature as above with an unnecessary leading zero on `r` and `s`
+    const PADDED_RS_SIGNATURE: [u8; 72] = hex!(
+        "3046022100784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c728579202210016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b"
+    );
+
+    /// Same signature as above with two unnecessary leading zeros on `r`
+    const DOUBLE_PADDED_R_SIGNATURE: [u8; 72] = hex!(
+        "304602220000784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792022016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b"
+    );
+
+    /// Same signature as above with the `SEQUENCE` length in the long form
+    const LONG_FORM_LENGTH_SIGNATURE: [u8; 71] = hex!(
+        "3081440220784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792022016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b"
+    );
+
+    #[test]
+    fn canonical() {
+        let strict = from_der_strict(&CANONICAL_SIGNATURE).unwrap();
+        assert_eq!(from_der(&CANONICAL_SIGNATURE).unwrap(), strict);
+        assert!(is_canonical(&CANONICAL_SIGNATURE));
+
+        // Agrees with the upstream strict parser
+        let der = crate::ecdsa::DerSignature::try_from(&CANONICAL_SIGNATURE[..]).unwrap();
+        assert!(is_canonical(der.as_bytes()));
+        assert_eq!(strict.to_der().as_bytes(), &CANONICAL_SIGNATURE);
+    }
+
+    #[test]
+    fn non_canonical() {
+        let mut trailing = [0u8; 71];
+        trailing[..70].copy_from_slice(&CANONICAL_SIGNATURE);
+
+        for bytes in [
+            &trailing[..],
+            &LONG_FORM_LENGTH_SIGNATURE,
+            &PADDED_R_SIGNATURE,
+            &PADDED_RS_SIGNATURE,
+            &CANONICAL_SIGNATURE[..69],
+        ] {
+            assert!(!is_canonical(bytes));
+            assert_eq!(from_der_strict(bytes), Err(P256Error::InvalidEncoding));
+            assert!(crate::ecdsa::Signature::from_der(bytes).is_err());
+        }
+
+        // The lenient parser still refuses non-minimal lengths
+        assert!(from_der(&LONG_FORM_LENGTH_SIGNATURE).is_err());
+    }
+
+    #[test]
+    fn extra_leading_zero() {
+        let expected = from_der_strict(&CANONICAL_SIGNATURE).unwrap();
+
+        assert!(from_der_strict(&PADDED_R_SIGNATURE).is_err());
+        assert_eq!(from_der(&PADDED_R_SIGNATURE).unwrap(), expected);
+
+        assert!(from_der_strict(&PADDED_RS_SIGNATURE).is_err());
+        assert_eq!(from_der(&PADDED_RS_SIGNATURE).unwrap(), expected);
+    }
+
+    #[test]
+    fn multiple_leading_zeros() {
+        assert_eq!(
+            from_der(&DOUBLE_PADDED_R_SIGNATURE),
+            Err(P256Error::InvalidEncoding)
+        );
+    }
+
+    #[test]
+    fn trailing_data() {
+        let mut bytes = [0u8; 71];
+        bytes[..70].copy_from_slice(&CANONICAL_SIGNATURE);
+        assert!(from_der(&bytes).is_err());
+    }
+}
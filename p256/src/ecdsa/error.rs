@@ -0,0 +1,79 @@
+//! Error types.
+
+use core::fmt::{self, Display};
+
+/// P-256-specific ECDSA errors.
+///
+/// Returned by APIs which want to tell callers *why* an operation failed,
+/// which the opaque [`Error`][`super::Error`] type can't express. It can
+/// always be converted into an [`Error`][`super::Error`] (e.g. with `?`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum P256Error {
+    /// Encoded point or signature is malformed.
+    InvalidEncoding,
+
+    /// Point is the identity, which is not a valid public key.
+    Identity,
+
+    /// Point does not satisfy the curve equation.
+    PointNotOnCurve,
+
+    /// Point coordinate is not less than the field modulus `p`.
+    CoordinateOutOfRange,
+
+    /// Signature has the wrong length.
+    InvalidSignatureLength,
+
+    /// Signature `r` component is zero or not less than the group order.
+    InvalidR,
+
+    /// Signature `s` component is zero or not less than the group order.
+    InvalidS,
+
+    /// Signature has a high `s` value and is therefore malleable.
+    Malleable,
+
+    /// Recovery ID is out of range.
+    InvalidRecoveryId,
+
+    /// Message digest reduces to zero modulo the group order.
+    ZeroDigest,
+
+    /// Signature is well-formed but does not verify.
+    VerificationFailed,
+}
+
+impl Display for P256Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::InvalidEncoding => "invalid encoding",
+            Self::Identity => "point is the identity",
+            Self::PointNotOnCurve => "point not on curve",
+            Self::CoordinateOutOfRange => "point coordinate out of range",
+            Self::InvalidSignatureLength => "invalid signature length",
+            Self::InvalidR => "signature r component out of range",
+            Self::InvalidS => "signature s component out of range",
+            Self::Malleable => "signature is malleable (high s)",
+            Self::InvalidRecoveryId => "invalid recovery ID",
+            Self::ZeroDigest => "message digest is zero",
+            Self::VerificationFailed => "signature verification failed",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for P256Error {}
+
+impl From<P256Error> for super::Error {
+    #[cfg(feature = "std")]
+    fn from(err: P256Error) -> Self {
+        Self::from_source(err)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn from(_: P256Error) -> Self {
+        Self::new()
+    }
+}
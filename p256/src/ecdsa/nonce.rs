@@ -0,0 +1,193 @@
+//! Nonce health checks.
+//!
+//! ECDSA is catastrophically fragile with respect to its per-signature nonce
+//! `k`: signing two different messages with the same `k` reveals the signing
+//! key to anyone who sees both signatures. The helpers in this module let
+//! signers audit their own output for such failures.
+
+use super::{Signature, VerifyingKey};
+use crate::{AffinePoint, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, U256};
+use elliptic_curve::{
+    ops::{Invert, Reduce},
+    subtle::{ConditionallySelectable, ConstantTimeEq, CtOption},
+    AffineXCoordinate,
+};
+
+/// Signatures whose `r` is below this bound are flagged by
+/// [`SuspiciousNonce::has_suspicious_nonce`].
+const SMALL_R_BOUND: u64 = 1 << 32;
+
+/// Nonces `k` in `1..=SMALL_K_MAX` are flagged by
+/// [`SuspiciousNonce::has_suspicious_nonce`].
+const SMALL_K_MAX: u64 = 16;
+
+/// Recover the signing key from two signatures over the message digests
+/// `z1` and `z2` which were produced with the same nonce `k` (i.e. which
+/// share the same `r`).
+///
+/// Uses the well-known formulas:
+///
+/// ```text
+/// k = (z1 - z2) / (s1 - s2)
+/// d = (s1 * k - z1) / r
+/// ```
+///
+/// Returns `None` if the signatures don't share the same `r`, or if `s1 == s2`
+/// (i.e. both signatures are over the same message). Both signatures must
+/// carry the `s` value produced by the signer; if either of them has been
+/// normalized to low-`s` form, the recovered key will be incorrect.
+pub fn recover_private_key_from_reuse(
+    sig1: &Signature,
+    z1: &Scalar,
+    sig2: &Signature,
+    z2: &Scalar,
+) -> CtOption<NonZeroScalar> {
+    let (r1, s1) = sig1.split_scalars();
+    let (r2, s2) = sig2.split_scalars();
+    let same_r = r1.ct_eq(&r2);
+
+    (*s1 - *s2).invert().and_then(|s_diff_inv| {
+        let k = (z1 - z2) * s_diff_inv;
+        let d = (*s1 * k - z1) * *r1.invert();
+
+        // Map a mismatched `r` to zero so `NonZeroScalar::new` rejects it
+        NonZeroScalar::new(Scalar::conditional_select(&Scalar::ZERO, &d, same_r))
+    })
+}
+
+/// Heuristic checks for nonces produced by a broken signer.
+///
+/// For a nonce `k` chosen uniformly at random, `r = x(k·G) mod n` is itself
+/// indistinguishable from random, so none of the conditions checked here
+/// ever happen by accident.
+///
+/// A `false` result does *not* imply that the nonce was generated correctly:
+/// this only catches a few obvious failure modes, and is intended for
+/// monitoring signatures, not as a substitute for RFC6979 or a sound RNG.
+/// The checks run in variable time.
+pub trait SuspiciousNonce {
+    /// Returns `true` if either of the following holds:
+    ///
+    /// - `r < 2^32`, i.e. `r` is a "small" integer, which suggests the
+    ///   signer is emitting garbage rather than computing `r` from a curve
+    ///   point;
+    /// - `r = x(k·G)` for some `k` in `1..=16`, i.e. the nonce (or its
+    ///   negation) is a small constant such as `1`.
+    ///
+    /// Catching a signer which used its own secret key as the nonce needs
+    /// the public key; see [`has_suspicious_nonce_for_key`].
+    fn has_suspicious_nonce(&self) -> bool;
+}
+
+impl SuspiciousNonce for Signature {
+    fn has_suspicious_nonce(&self) -> bool {
+        let (r, _) = self.split_scalars();
+        let r = r.as_ref();
+
+        if *r < Scalar::from(SMALL_R_BOUND) {
+            return true;
+        }
+
+        let mut point = ProjectivePoint::IDENTITY;
+        for _ in 0..SMALL_K_MAX {
+            point += ProjectivePoint::GENERATOR;
+            if x_mod_n(&point.to_affine()) == *r {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Heuristically check whether `signature` (made by `public_key`) was
+/// produced by a broken signer.
+///
+/// In addition to [`SuspiciousNonce::has_suspicious_nonce`], this returns
+/// `true` if `r = x(Q)` where `Q` is `public_key`, i.e. the signer used its
+/// own secret key `±d` as the nonce. That check can't be made from the
+/// signature alone, which is why this takes the key as well.
+pub fn has_suspicious_nonce_for_key(signature: &Signature, public_key: &VerifyingKey) -> bool {
+    let (r, _) = signature.split_scalars();
+
+    signature.has_suspicious_nonce()
+        || x_mod_n(PublicKey::from(public_key).as_affine()) == *r.as_ref()
+}
+
+/// Reduce the affine x-coordinate of `point` modulo the group order.
+fn x_mod_n(point: &AffinePoint) -> Scalar {
+    <Scalar as Reduce<U256>>::from_be_bytes_reduced(point.x())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_suspicious_nonce_for_key, recover_private_key_from_reuse, SuspiciousNonce};
+    use crate::{
+        ecdsa::{Signature, SigningKey, VerifyingKey},
+        NonZeroScalar, Scalar,
+    };
+    use ecdsa_core::hazmat::SignPrimitive;
+    use elliptic_curve::{ops::Reduce, rand_core::OsRng, Field};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn recover_from_reused_nonce() {
+        let d = NonZeroScalar::random(&mut OsRng);
+        let k = Scalar::random(&mut OsRng);
+
+        let z1 = Sha256::digest(b"first message");
+        let z2 = Sha256::digest(b"second message");
+        let (sig1, _) = d.try_sign_prehashed(k, z1).unwrap();
+        let (sig2, _) = d.try_sign_prehashed(k, z2).unwrap();
+
+        let z1 = <Scalar as Reduce<crate::U256>>::from_be_bytes_reduced(z1);
+        let z2 = <Scalar as Reduce<crate::U256>>::from_be_bytes_reduced(z2);
+        let recovered = recover_private_key_from_reuse(&sig1, &z1, &sig2, &z2).unwrap();
+        assert_eq!(*recovered, *d);
+
+        // Same message: `s1 == s2`
+        assert!(bool::from(
+            recover_private_key_from_reuse(&sig1, &z1, &sig1, &z1).is_none()
+        ));
+
+        // Different nonces: `r1 != r2`
+        let (sig3, _) = d
+            .try_sign_prehashed(Scalar::random(&mut OsRng), z2.to_bytes())
+            .unwrap();
+        assert!(bool::from(
+            recover_private_key_from_reuse(&sig1, &z1, &sig3, &z2).is_none()
+        ));
+    }
+
+    #[test]
+    fn suspicious_nonce() {
+        let d = NonZeroScalar::random(&mut OsRng);
+        let public_key = VerifyingKey::from(&SigningKey::from(d));
+        let z = Sha256::digest(b"test message");
+
+        // Honestly generated nonces aren't flagged
+        let (signature, _) = d.try_sign_prehashed(Scalar::random(&mut OsRng), z).unwrap();
+        assert!(!signature.has_suspicious_nonce());
+        assert!(!has_suspicious_nonce_for_key(&signature, &public_key));
+
+        // `k = 1`, its negation, and other small nonces
+        for k in [Scalar::ONE, -Scalar::ONE, Scalar::from(16u64)] {
+            let (signature, _) = d.try_sign_prehashed(k, z).unwrap();
+            assert!(signature.has_suspicious_nonce());
+            assert!(has_suspicious_nonce_for_key(&signature, &public_key));
+        }
+
+        // Nonce equal to the secret key, which only the key-aware check sees
+        let (signature, _) = d.try_sign_prehashed(*d, z).unwrap();
+        assert!(!signature.has_suspicious_nonce());
+        assert!(has_suspicious_nonce_for_key(&signature, &public_key));
+
+        // Small `r`, which can't be produced by signing, only constructed
+        let mut bytes = [0u8; 64];
+        bytes[31] = 7;
+        bytes[32..].copy_from_slice(&signature.as_ref()[32..]);
+        let signature = Signature::try_from(&bytes[..]).unwrap();
+        assert!(signature.has_suspicious_nonce());
+        assert!(has_suspicious_nonce_for_key(&signature, &public_key));
+    }
+}
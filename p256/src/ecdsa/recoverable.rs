@@ -39,21 +39,40 @@ use core::fmt::{self, Debug};
 use ecdsa_core::{signature::Signature as _, Error, Result};
 use elliptic_curve::subtle::Choice;
 
+#[cfg(feature = "ecdsa")]
+use elliptic_curve::subtle::{ConstantTimeEq, CtOption};
+
 #[cfg(feature = "ecdsa")]
 use crate::{
     ecdsa::{
         signature::digest::{Digest, FixedOutput},
         verify::VerifyingKey,
+        P256Error,
     },
     elliptic_curve::{
         bigint::U256,
         consts::U32,
         ops::{Invert, LinearCombination, Reduce},
-        DecompressPoint,
+        AffineXCoordinate, DecompressPoint,
     },
     AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar,
 };
 
+#[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+use sha3::Keccak256;
+
+#[cfg(all(feature = "ecdh", feature = "ecdsa"))]
+use {
+    crate::ecdh::{diffie_hellman, SharedSecret},
+    ecdsa_core::hazmat::VerifyPrimitive,
+};
+
+#[cfg(all(feature = "alloc", feature = "ecdsa", feature = "keccak256"))]
+use alloc::{vec, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
 #[cfg(feature = "sha256")]
 use sha2::Sha256;
 
@@ -69,6 +88,13 @@ pub const SIZE: usize = 65;
 /// - `r`: 32-byte integer, big endian
 /// - `s`: 32-byte integer, big endian
 /// - `v`: 1-byte recovery [`Id`]
+///
+/// # `serde` support
+///
+/// When the `serde` feature of this crate is enabled, the `Serialize` and
+/// `Deserialize` traits are impl'd for this type. Binary formats use the
+/// 65-byte encoding above as a fixed-size array with no length prefix, and
+/// text formats use a hexadecimal encoding.
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub struct Signature {
     bytes: [u8; SIZE],
@@ -87,9 +113,53 @@ impl Signature {
         Ok(Self { bytes })
     }
 
+    /// Parse a recoverable signature from its 65-byte encoding, rejecting
+    /// high-`s` signatures and reporting why the input was rejected.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn from_bytes_strict(bytes: &[u8]) -> core::result::Result<Self, P256Error> {
+        if bytes.len() != SIZE {
+            return Err(P256Error::InvalidEncoding);
+        }
+
+        let signature =
+            super::Signature::try_from(&bytes[..64]).map_err(|_| P256Error::InvalidEncoding)?;
+
+        if signature.normalize_s().is_some() {
+            return Err(P256Error::Malleable);
+        }
+
+        let recovery_id = Id::new(bytes[64]).map_err(|_| P256Error::InvalidRecoveryId)?;
+        Self::new(&signature, recovery_id).map_err(|_| P256Error::InvalidEncoding)
+    }
+
+    /// Parse a Bitcoin "signed message"-style signature.
+    ///
+    /// Unlike the Ethereum-style layout used by [`Signature`], this format
+    /// places a 1-byte header *before* the signature (65-bytes total):
+    ///
+    /// - header: recovery [`Id`] plus `27`, or plus `31` if the signer's
+    ///   public key is to be serialized with point compression
+    /// - `r`: 32-byte integer, big endian
+    /// - `s`: 32-byte integer, big endian
+    ///
+    /// Returns the signature along with the compressed flag.
+    pub fn from_bitcoin_message_sig(bytes: &[u8; SIZE]) -> Result<(Self, bool)> {
+        let (recovery_id, compressed) = match bytes[0] {
+            header @ 27..=30 => (header - 27, false),
+            header @ 31..=34 => (header - 31, true),
+            _ => return Err(Error::new()),
+        };
+
+        let signature = super::Signature::try_from(&bytes[1..])?;
+        let signature = Self::new(&signature, Id::new(recovery_id)?)?;
+        Ok((signature, compressed))
+    }
+
     /// Get the recovery [`Id`] for this signature
     pub fn recovery_id(self) -> Id {
-        self.bytes[64].try_into().expect("invalid recovery ID")
+        // All constructors take an `Id`, so the byte is always valid
+        Id(self.bytes[64])
     }
 
     /// Given a public key, message, and signature, use trial recovery
@@ -123,17 +193,37 @@ impl Signature {
     where
         D: Clone + Digest + FixedOutput<OutputSize = U32>,
     {
-        use ecdsa_core::signature::DigestVerifier;
-
         let signature = signature.normalize_s().unwrap_or(*signature);
+        Self::from_digest_trial_recovery_preserve(public_key, digest, &signature)
+    }
+
+    /// Given a public key, message digest, and signature, use trial recovery
+    /// to determine if a suitable recovery ID exists, or return an error
+    /// otherwise.
+    ///
+    /// Unlike [`Signature::from_digest_trial_recovery`], `s` is *not*
+    /// normalized first, so the resulting signature has exactly the same
+    /// `r` and `s` as the provided one. Note that the recovery ID of a
+    /// high-`s` signature has the opposite parity of its normalized form.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn from_digest_trial_recovery_preserve<D>(
+        public_key: &VerifyingKey,
+        digest: D,
+        signature: &super::Signature,
+    ) -> Result<Self>
+    where
+        D: Clone + Digest + FixedOutput<OutputSize = U32>,
+    {
+        use ecdsa_core::signature::DigestVerifier;
 
         for recovery_id in 0..=1 {
-            if let Ok(recoverable_signature) = Signature::new(&signature, Id(recovery_id)) {
+            if let Ok(recoverable_signature) = Signature::new(signature, Id(recovery_id)) {
                 if let Ok(recovered_key) =
                     recoverable_signature.recover_verifying_key_from_digest(digest.clone())
                 {
                     if public_key == &recovered_key
-                        && public_key.verify_digest(digest.clone(), &signature).is_ok()
+                        && public_key.verify_digest(digest.clone(), signature).is_ok()
                     {
                         return Ok(recoverable_signature);
                     }
@@ -166,30 +256,153 @@ impl Signature {
 
     /// Recover the public key used to create the given signature as a
     /// [`VerifyingKey`] from the raw bytes of a message digest.
+    ///
+    /// See [`Signature::recover_verifying_key_from_prehash`] for the possible
+    /// failures, which are available as the error's source when the `std`
+    /// feature is enabled.
     #[cfg(feature = "ecdsa")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
-    #[allow(non_snake_case, clippy::many_single_char_names)]
     pub fn recover_verifying_key_from_digest_bytes(
         &self,
         digest_bytes: &FieldBytes,
     ) -> Result<VerifyingKey> {
+        Ok(self.recover_verifying_key_from_prehash(&(*digest_bytes).into())?)
+    }
+
+    /// Recover the public key used to create the given signature as a
+    /// [`VerifyingKey`] from a 32-byte message digest, reporting why recovery
+    /// failed.
+    ///
+    /// # Errors
+    ///
+    /// - [`P256Error::PointNotOnCurve`] if `r` is not the `x`-coordinate of
+    ///   any curve point, i.e. the signature can't have been produced by
+    ///   anyone.
+    /// - [`P256Error::Identity`] if the recovered point is the identity.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    #[allow(non_snake_case, clippy::many_single_char_names)]
+    pub fn recover_verifying_key_from_prehash(
+        &self,
+        prehash: &[u8; 32],
+    ) -> core::result::Result<VerifyingKey, P256Error> {
         let r = self.r();
         let s = self.s();
-        let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(*digest_bytes);
-        let R = AffinePoint::decompress(&r.to_bytes(), self.recovery_id().is_y_odd());
-
-        if R.is_none().into() {
-            return Err(Error::new());
-        }
+        let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced((*prehash).into());
+        let R = Option::<AffinePoint>::from(self.r_point(self.recovery_id()))
+            .ok_or(P256Error::PointNotOnCurve)?;
 
-        let R = ProjectivePoint::from(R.unwrap());
+        let R = ProjectivePoint::from(R);
         let r_inv = *r.invert();
         let u1 = -(r_inv * z);
         let u2 = r_inv * *s;
         let pk = ProjectivePoint::lincomb(&ProjectivePoint::GENERATOR, &u1, &R, &u2);
 
         // TODO(tarcieri): ensure the signature verifies?
-        VerifyingKey::try_from(pk)
+        VerifyingKey::try_from(pk).map_err(|_| P256Error::Identity)
+    }
+
+    /// Recover the [`VerifyingKey`] used to create this signature over the
+    /// given 32-byte message hash, returning it only if it is one of the
+    /// `allowed` keys.
+    ///
+    /// The key is recovered once and compared against every allowed key in
+    /// constant time, so the scan doesn't reveal which key matched.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn recover_if_in_set(
+        &self,
+        msg_hash: &[u8; 32],
+        allowed: &[VerifyingKey],
+    ) -> Option<VerifyingKey> {
+        let recovered = self.recover_verifying_key_from_prehash(msg_hash).ok()?;
+        let found = allowed
+            .iter()
+            .fold(Choice::from(0), |found, key| found | key.ct_eq(&recovered));
+
+        Option::from(CtOption::new(recovered, found))
+    }
+
+    /// Recover the [`VerifyingKey`] used to create this signature over the
+    /// given 32-byte message hash, and perform ECDH between it and
+    /// `our_secret`.
+    ///
+    /// The recovered key is validated before it is used for key agreement:
+    /// it must not be the identity and the signature must verify under it.
+    #[cfg(all(feature = "ecdh", feature = "ecdsa"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn recover_and_derive(
+        &self,
+        msg_hash: &[u8; 32],
+        our_secret: &NonZeroScalar,
+    ) -> Result<SharedSecret> {
+        let verifying_key = self.recover_verifying_key_from_prehash(msg_hash)?;
+        let public_key = crate::PublicKey::from(&verifying_key);
+
+        public_key
+            .as_affine()
+            .verify_prehashed((*msg_hash).into(), &(*self).into())?;
+
+        Ok(diffie_hellman(our_secret, public_key.as_affine()))
+    }
+
+    /// Compute the point `R` whose `x`-coordinate is this signature's `r`
+    /// component and whose `y`-coordinate has the parity given by `id`.
+    ///
+    /// This is the point used during public key recovery. It only depends on
+    /// the signature, so it can be computed once and reused, e.g. when trying
+    /// a signature against several candidate keys.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn r_point(&self, id: Id) -> CtOption<AffinePoint> {
+        AffinePoint::decompress(&self.r().to_bytes(), id.is_y_odd())
+    }
+
+    /// Get the field element bytes of the `x`-coordinate of the point `R`
+    /// used during public key recovery (see [`Signature::r_point`]), e.g.
+    /// for circuits which operate on base field elements rather than on the
+    /// scalar `r = x mod n`.
+    ///
+    /// [`Id`] only encodes the parity of `R.y`, so `R.x` is always taken to
+    /// be `r` itself (never `r + n`): reducing the result modulo `n` yields
+    /// `r`. Returns none if `r` is not the `x`-coordinate of any curve
+    /// point.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn recovered_r_point_x(&self) -> CtOption<FieldBytes> {
+        self.r_point(self.recovery_id()).map(|point| point.x())
+    }
+
+    /// Recover the Ethereum address of the key used to create this signature
+    /// over the given 32-byte message hash.
+    ///
+    /// The address is the last 20 bytes of the Keccak-256 hash of the
+    /// recovered key's uncompressed SEC1 encoding (without the leading tag
+    /// byte).
+    #[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "keccak256")))]
+    pub fn recover_eth_address(&self, msg_hash: &[u8; 32]) -> Result<[u8; 20]> {
+        let public_key = self.recover_verifying_key_from_digest_bytes(msg_hash.into())?;
+        Ok(eth_address(crate::PublicKey::from(&public_key).as_affine()))
+    }
+
+    /// Verify that this signature over the given 32-byte message hash was
+    /// produced by the key with the given Ethereum address.
+    ///
+    /// This mirrors the common Solidity pattern of comparing the result of
+    /// `ecrecover` against a known signer: no public key is needed, as it is
+    /// recovered from the signature. See [`Signature::recover_eth_address`].
+    #[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "keccak256")))]
+    pub fn verify_eth_address(&self, msg_hash: &[u8; 32], address: &[u8; 20]) -> Result<()> {
+        if &self.recover_eth_address(msg_hash)? == address {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
     }
 
     /// Parse the `r` component of this signature to a [`NonZeroScalar`]
@@ -207,6 +420,237 @@ impl Signature {
         NonZeroScalar::try_from(&self.bytes[32..64])
             .expect("s-component ensured valid in constructor")
     }
+
+    /// Get the low-`s` form of this signature, flipping the recovery [`Id`]
+    /// to match if `s` is negated so the same key is still recovered.
+    ///
+    /// Returns the signature unchanged if its `s` is already low.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn canonicalize(&self) -> Self {
+        match super::Signature::from(*self).normalize_s() {
+            Some(low_s) => Self::new(&low_s, Id(self.recovery_id().0 ^ 1))
+                .expect("signature ensured valid in constructor"),
+            None => *self,
+        }
+    }
+}
+
+/// Verify that the Ethereum address of the key which produced the given
+/// signature over `msg_hash` is `expected_address`.
+///
+/// This is the operation performed by the EVM when calling the `ecrecover`
+/// precompile and comparing its result against a known address: the
+/// [`VerifyingKey`] is recovered from `(r, s, v)`, where `v` is the recovery
+/// [`Id`] plus `27`, and its address is derived as the last 20 bytes of the
+/// Keccak-256 hash of its uncompressed SEC1 encoding (without the leading
+/// tag byte).
+///
+/// As with `ecrecover`, high-`s` signatures are accepted.
+#[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "keccak256")))]
+pub fn verify_eth(
+    msg_hash: &[u8; 32],
+    r: &FieldBytes,
+    s: &FieldBytes,
+    v: u8,
+    expected_address: &[u8; 20],
+) -> Result<()> {
+    let recovery_id = Id::from_eth_byte(v)?;
+    let signature = super::Signature::from_scalars(*r, *s)?;
+    Signature::new(&signature, recovery_id)?.verify_eth_address(msg_hash, expected_address)
+}
+
+/// Item verified by [`verify_eth_batch`]: a
+/// `(sender_address, msg_hash, r, s, v)` tuple with the same meaning as the
+/// arguments of [`verify_eth`].
+#[cfg(all(feature = "alloc", feature = "ecdsa", feature = "keccak256"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "keccak256")))]
+pub type EthBatchItem = ([u8; 20], [u8; 32], FieldBytes, FieldBytes, u8);
+
+/// Verify a batch of Ethereum-style signatures (see [`EthBatchItem`]).
+///
+/// Recovering a key from a signature already proves that the signature is
+/// valid under that key, so the only check left is comparing its address
+/// with the expected sender. What batching buys is sharing the expensive
+/// inversions: the `r⁻¹` of every signature is computed with a single scalar
+/// inversion, and the recovered keys are converted to affine coordinates
+/// with a single field inversion.
+///
+/// Returns the indices of the items which fail to verify (in ascending
+/// order), or `Ok(())` if they all verify, including when `items` is empty.
+#[cfg(all(feature = "alloc", feature = "ecdsa", feature = "keccak256"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "keccak256")))]
+#[allow(non_snake_case)]
+pub fn verify_eth_batch(items: &[EthBatchItem]) -> core::result::Result<(), Vec<usize>> {
+    let parse = |msg_hash: &[u8; 32], r: &FieldBytes, s: &FieldBytes, v: u8| {
+        let recovery_id = Id::from_eth_byte(v)?;
+        let signature = Signature::new(&super::Signature::from_scalars(*r, *s)?, recovery_id)?;
+        let R =
+            Option::<AffinePoint>::from(signature.r_point(recovery_id)).ok_or_else(Error::new)?;
+        let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced((*msg_hash).into());
+        Ok::<_, Error>((*signature.r(), *signature.s(), z, R))
+    };
+
+    let mut failed = Vec::new();
+    let mut parsed = Vec::with_capacity(items.len());
+
+    for (i, (_, msg_hash, r, s, v)) in items.iter().enumerate() {
+        match parse(msg_hash, r, s, *v) {
+            Ok(item) => parsed.push((i, item)),
+            Err(_) => failed.push(i),
+        }
+    }
+
+    // Invert every `r` at once. They are all nonzero, so this can't fail.
+    let mut r_invs = parsed.iter().map(|(_, (r, ..))| *r).collect::<Vec<_>>();
+    let _ = Scalar::batch_invert(&mut r_invs);
+    let mut public_keys = vec![ProjectivePoint::IDENTITY; parsed.len()];
+
+    for ((_, (_, s, z, R)), (r_inv, public_key)) in
+        parsed.iter().zip(r_invs.iter().zip(public_keys.iter_mut()))
+    {
+        let u1 = -(r_inv * z);
+        let u2 = r_inv * s;
+        *public_key = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &u1,
+            &ProjectivePoint::from(*R),
+            &u2,
+        );
+    }
+
+    let affine = ProjectivePoint::to_affine_batch(&public_keys);
+
+    for ((i, _), public_key) in parsed.iter().zip(&affine) {
+        if bool::from(public_key.is_identity()) || eth_address(public_key) != items[*i].0 {
+            failed.push(*i);
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        failed.sort_unstable();
+        Err(failed)
+    }
+}
+
+/// Compute the Ethereum address of the given public key: the last 20 bytes
+/// of the Keccak-256 hash of its uncompressed SEC1 encoding (without the
+/// leading tag byte).
+#[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+fn eth_address(public_key: &AffinePoint) -> [u8; 20] {
+    use crate::elliptic_curve::sec1::ToEncodedPoint;
+
+    let hash = Keccak256::digest(&public_key.to_encoded_point(false).as_bytes()[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Recover the [`VerifyingKey`] which produced the signature `(r, s)` with
+/// recovery ID `v` over the 32-byte message digest `z`.
+///
+/// This is the same operation as
+/// [`Signature::recover_verifying_key_from_digest_bytes`], exposed as a pure
+/// function of its four inputs in the style of the `ecrecover` precompile.
+/// `v` may be either the raw recovery [`Id`] (`0` or `1`) or the
+/// Ethereum-style value `27` or `28`.
+///
+/// Returns an error if `r` or `s` is zero or not less than the group order,
+/// or if `v` is out of range.
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub fn ecdsa_recover(r: &[u8; 32], s: &[u8; 32], v: u8, z: &[u8; 32]) -> Result<VerifyingKey> {
+    let recovery_id = Id::new(match v {
+        27 | 28 => v - 27,
+        _ => v,
+    })?;
+    let signature = super::Signature::from_scalars(FieldBytes::from(*r), FieldBytes::from(*s))?;
+    Signature::new(&signature, recovery_id)?.recover_verifying_key_from_digest_bytes(&(*z).into())
+}
+
+/// Iterator adapter which recovers a [`VerifyingKey`] from each
+/// `(signature, message hash)` pair yielded by the wrapped iterator.
+///
+/// This is a streaming counterpart to recovering keys one at a time with
+/// [`Signature::recover_verifying_key_from_digest_bytes`]: each item is
+/// recovered lazily as the iterator is advanced, and a failed recovery
+/// yields an error for that item without ending the iteration.
+///
+/// ```
+/// # #[cfg(all(feature = "ecdsa", feature = "sha256"))]
+/// # {
+/// use p256::ecdsa::{recoverable::{self, RecoverIter}, signature::Signer, SigningKey};
+/// use rand_core::OsRng;
+/// use sha2::{Digest, Sha256};
+///
+/// let signing_key = SigningKey::random(&mut OsRng);
+/// let items = [b"first".as_slice(), b"second"].map(|msg| {
+///     let signature: recoverable::Signature = signing_key.sign(msg);
+///     (signature, <[u8; 32]>::from(Sha256::digest(msg)))
+/// });
+///
+/// for key in RecoverIter::new(items.into_iter()) {
+///     assert_eq!(key.unwrap(), signing_key.verifying_key());
+/// }
+/// # }
+/// ```
+#[cfg(feature = "ecdsa")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[derive(Clone, Debug)]
+pub struct RecoverIter<I> {
+    inner: I,
+}
+
+#[cfg(feature = "ecdsa")]
+impl<I> RecoverIter<I>
+where
+    I: Iterator<Item = (Signature, [u8; 32])>,
+{
+    /// Wrap an iterator of `(signature, message hash)` pairs.
+    pub fn new(inner: I) -> Self {
+        Self { inner }
+    }
+
+    /// Unwrap the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl<I> Iterator for RecoverIter<I>
+where
+    I: Iterator<Item = (Signature, [u8; 32])>,
+{
+    type Item = Result<VerifyingKey>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(signature, msg_hash)| {
+            signature.recover_verifying_key_from_digest_bytes(&msg_hash.into())
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl<I> ExactSizeIterator for RecoverIter<I> where I: ExactSizeIterator<Item = (Signature, [u8; 32])>
+{}
+
+#[cfg(feature = "ecdsa")]
+impl<I> core::iter::FusedIterator for RecoverIter<I> where
+    I: core::iter::FusedIterator<Item = (Signature, [u8; 32])>
+{
 }
 
 impl ecdsa_core::signature::Signature for Signature {
@@ -223,7 +667,7 @@ impl AsRef<[u8]> for Signature {
 
 impl Debug for Signature {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "RecoverableSignature {{ bytes: {:?}) }}", self.as_ref())
+        write!(f, "RecoverableSignature {{ bytes: {:?} }}", self.as_ref())
     }
 }
 
@@ -243,7 +687,31 @@ impl TryFrom<&[u8]> for Signature {
 
 impl From<Signature> for super::Signature {
     fn from(sig: Signature) -> Self {
-        Self::from_bytes(&sig.bytes[..64]).unwrap()
+        Self::from_bytes(&sig.bytes[..64]).expect("signature ensured valid in constructor")
+    }
+}
+
+/// Split a recoverable signature into the upstream [`ecdsa_core`] types.
+///
+/// Together with the inverse `TryFrom` conversion, this round-trips the
+/// 65-byte encoding exactly, including for high-`s` signatures.
+impl From<Signature> for (super::Signature, ecdsa_core::RecoveryId) {
+    fn from(sig: Signature) -> Self {
+        (sig.into(), sig.recovery_id().into())
+    }
+}
+
+/// Combine a signature with an upstream [`ecdsa_core::RecoveryId`].
+///
+/// Fails if the recovery ID is "x-reduced", as [`Id`] can't represent
+/// those yet.
+impl TryFrom<(super::Signature, ecdsa_core::RecoveryId)> for Signature {
+    type Error = Error;
+
+    fn try_from(
+        (signature, recovery_id): (super::Signature, ecdsa_core::RecoveryId),
+    ) -> Result<Self> {
+        Self::new(&signature, recovery_id.try_into()?)
     }
 }
 
@@ -252,6 +720,30 @@ impl ecdsa_core::signature::PrehashSignature for Signature {
     type Digest = Sha256;
 }
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::array::serialize_hex_upper_or_bin(&self.bytes, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; SIZE];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Self::try_from(&bytes[..]).map_err(de::Error::custom)
+    }
+}
+
 /// Identifier used to compute a [`VerifyingKey`] from a [`Signature`].
 ///
 /// In practice these values are always either `0` or `1`, and indicate
@@ -275,6 +767,37 @@ impl Id {
         }
     }
 
+    /// Create a new [`Id`] from the tag byte of a compressed SEC1 point
+    /// (`0x02` if `y` is even, `0x03` if it is odd).
+    pub fn from_sec1_tag(tag: u8) -> Result<Self> {
+        match tag {
+            0x02 | 0x03 => Ok(Self(tag - 0x02)),
+            _ => Err(Error::new()),
+        }
+    }
+
+    /// Get the tag byte of the compressed SEC1 encoding of the point this
+    /// [`Id`] refers to (`0x02` if `y` is even, `0x03` if it is odd).
+    pub fn to_sec1_tag(self) -> u8 {
+        0x02 + self.0
+    }
+
+    /// Create a new [`Id`] from a legacy Ethereum `v` byte, i.e. `27` or
+    /// `28`, without [EIP-155] chain ID handling.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn from_eth_byte(v: u8) -> Result<Self> {
+        match v {
+            27 | 28 => Ok(Self(v - 27)),
+            _ => Err(Error::new()),
+        }
+    }
+
+    /// Get the legacy Ethereum `v` byte for this [`Id`], i.e. `27` or `28`.
+    pub fn as_eth_byte(self) -> u8 {
+        self.0 + 27
+    }
+
     /// Is `y` odd?
     fn is_y_odd(self) -> Choice {
         self.0.into()
@@ -321,6 +844,7 @@ mod tests {
     use crate::{ecdsa::sign::SigningKey, EncodedPoint};
     use ecdsa_core::signature::Signer;
     use hex_literal::hex;
+    use proptest::prelude::*;
     use sha2::{Digest, Sha256};
 
     /// Signature recovery test vectors
@@ -349,6 +873,498 @@ mod tests {
         },
     ];
 
+    #[test]
+    fn from_bytes_strict() {
+        use crate::ecdsa::P256Error;
+
+        let low_s = RECOVERY_TEST_VECTORS[1].sig;
+        assert!(Signature::from_bytes_strict(&low_s).is_ok());
+        assert_eq!(
+            Signature::from_bytes_strict(&low_s[..64]),
+            Err(P256Error::InvalidEncoding)
+        );
+
+        let mut bad_id = low_s;
+        bad_id[64] = 2;
+        assert_eq!(
+            Signature::from_bytes_strict(&bad_id),
+            Err(P256Error::InvalidRecoveryId)
+        );
+
+        let high_s = RECOVERY_TEST_VECTORS[0].sig;
+        assert_eq!(
+            Signature::from_bytes_strict(&high_s),
+            Err(P256Error::Malleable)
+        );
+    }
+
+    #[test]
+    fn from_bitcoin_message_sig() {
+        for (vector, recovery_id) in RECOVERY_TEST_VECTORS.iter().zip([0u8, 1]) {
+            let mut bytes = [0u8; 65];
+            bytes[1..].copy_from_slice(&vector.sig[..64]);
+
+            bytes[0] = 31 + recovery_id;
+            let (sig, compressed) = Signature::from_bitcoin_message_sig(&bytes).unwrap();
+            assert!(compressed);
+            assert_eq!(sig.as_ref(), &vector.sig[..]);
+
+            let pk = sig.recover_verifying_key(vector.msg).unwrap();
+            assert_eq!(&vector.pk[..], EncodedPoint::from(&pk).as_bytes());
+
+            bytes[0] = 27 + recovery_id;
+            let (sig, compressed) = Signature::from_bitcoin_message_sig(&bytes).unwrap();
+            assert!(!compressed);
+            assert_eq!(sig.as_ref(), &vector.sig[..]);
+        }
+
+        // Ethereum-style layout with the recovery ID last
+        assert!(Signature::from_bitcoin_message_sig(&RECOVERY_TEST_VECTORS[0].sig).is_err());
+
+        // Recovery IDs 2 and 3 are unsupported
+        let mut bytes = [0u8; 65];
+        bytes[1..].copy_from_slice(&RECOVERY_TEST_VECTORS[0].sig[..64]);
+        for header in [29, 30, 33, 34, 35] {
+            bytes[0] = header;
+            assert!(Signature::from_bitcoin_message_sig(&bytes).is_err());
+        }
+    }
+
+    #[cfg(feature = "keccak256")]
+    #[test]
+    fn recover_eth_address() {
+        let address = hex!("11465a24e9f544ac8572c91f8bdc3f1a4b15243f");
+
+        for vector in RECOVERY_TEST_VECTORS {
+            let sig = Signature::try_from(&vector.sig[..]).unwrap();
+            let msg_hash = Sha256::digest(vector.msg).into();
+            assert_eq!(sig.recover_eth_address(&msg_hash).unwrap(), address);
+            assert_ne!(sig.recover_eth_address(&[0; 32]).unwrap(), address);
+        }
+    }
+
+    #[cfg(feature = "keccak256")]
+    #[test]
+    fn verify_eth_address() {
+        let address = hex!("11465a24e9f544ac8572c91f8bdc3f1a4b15243f");
+        let mut wrong_address = address;
+        wrong_address[0] ^= 0x80;
+
+        for vector in RECOVERY_TEST_VECTORS {
+            let sig = Signature::try_from(&vector.sig[..]).unwrap();
+            let msg_hash = Sha256::digest(vector.msg).into();
+
+            assert!(sig.verify_eth_address(&msg_hash, &address).is_ok());
+            assert!(sig.verify_eth_address(&msg_hash, &wrong_address).is_err());
+            assert!(sig.verify_eth_address(&[0; 32], &address).is_err());
+        }
+    }
+
+    #[cfg(feature = "keccak256")]
+    #[test]
+    fn verify_eth() {
+        use super::verify_eth;
+        use crate::FieldBytes;
+
+        let vector = &RECOVERY_TEST_VECTORS[1];
+        let msg_hash: [u8; 32] = Sha256::digest(vector.msg).into();
+        let r = FieldBytes::clone_from_slice(&vector.sig[..32]);
+        let s = FieldBytes::clone_from_slice(&vector.sig[32..64]);
+        let v = vector.sig[64] + 27;
+        let address = hex!("11465a24e9f544ac8572c91f8bdc3f1a4b15243f");
+
+        assert!(verify_eth(&msg_hash, &r, &s, v, &address).is_ok());
+
+        let mut wrong_address = address;
+        wrong_address[19] ^= 1;
+        assert!(verify_eth(&msg_hash, &r, &s, v, &wrong_address).is_err());
+        assert!(verify_eth(&msg_hash, &r, &s, v ^ 1, &address).is_err());
+        assert!(verify_eth(&msg_hash, &r, &s, vector.sig[64], &address).is_err());
+        assert!(verify_eth(&[0; 32], &r, &s, v, &address).is_err());
+    }
+
+    #[test]
+    fn sec1_tag() {
+        use super::Id;
+        use crate::{elliptic_curve::sec1::ToEncodedPoint, AffinePoint};
+
+        for byte in 0..=1 {
+            let id = Id::new(byte).unwrap();
+            assert_eq!(id.to_sec1_tag(), 0x02 + byte);
+            assert_eq!(u8::from(Id::from_sec1_tag(id.to_sec1_tag()).unwrap()), byte);
+        }
+
+        for tag in [0x00, 0x01, 0x04, 0x06, 0x07] {
+            assert!(Id::from_sec1_tag(tag).is_err());
+        }
+
+        // The tag matches the parity of `y`
+        for point in [AffinePoint::GENERATOR, -AffinePoint::GENERATOR] {
+            let tag = point.to_encoded_point(true).as_bytes()[0];
+            let id = Id::from_sec1_tag(tag).unwrap();
+            assert_eq!(u8::from(id), u8::from(point.is_y_odd()));
+            assert_eq!(id.to_sec1_tag(), tag);
+        }
+    }
+
+    #[test]
+    fn eth_byte() {
+        use super::Id;
+
+        for byte in 0..=1 {
+            let id = Id::new(byte).unwrap();
+            assert_eq!(id.as_eth_byte(), 27 + byte);
+            assert_eq!(u8::from(Id::from_eth_byte(id.as_eth_byte()).unwrap()), byte);
+        }
+
+        for v in [0, 1, 26, 29, 35, 36, 255] {
+            assert!(Id::from_eth_byte(v).is_err());
+        }
+    }
+
+    #[test]
+    fn trial_recovery_preserve() {
+        use crate::ecdsa::{self, VerifyingKey};
+
+        for vector in RECOVERY_TEST_VECTORS {
+            let pk = VerifyingKey::from_sec1_bytes(&vector.pk).unwrap();
+            let sig = ecdsa::Signature::try_from(&vector.sig[..64]).unwrap();
+            let digest = Sha256::new_with_prefix(vector.msg);
+            let high_s = sig.normalize_s().is_some();
+
+            let preserved =
+                Signature::from_digest_trial_recovery_preserve(&pk, digest.clone(), &sig).unwrap();
+            assert_eq!(preserved.as_ref(), &vector.sig[..]);
+
+            let normalized = Signature::from_digest_trial_recovery(&pk, digest, &sig).unwrap();
+            assert!(ecdsa::Signature::from(normalized).normalize_s().is_none());
+
+            // Normalizing `s` flips the parity of the recovery ID
+            assert_eq!(
+                u8::from(normalized.recovery_id()),
+                u8::from(preserved.recovery_id()) ^ u8::from(high_s)
+            );
+            assert_eq!(normalized.as_ref()[..64] == vector.sig[..64], !high_s);
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "keccak256"))]
+    #[test]
+    fn verify_eth_batch() {
+        use super::{verify_eth, verify_eth_batch};
+        use crate::FieldBytes;
+        use alloc::{vec, vec::Vec};
+        use elliptic_curve::rand_core::OsRng;
+
+        let mut items = (0u8..6)
+            .map(|i| {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let msg_hash = [i; 32];
+                let sig = signing_key
+                    .sign_prehash_recoverable(msg_hash.into())
+                    .unwrap();
+                let address = sig.recover_eth_address(&msg_hash).unwrap();
+                let r = FieldBytes::clone_from_slice(&sig.as_ref()[..32]);
+                let s = FieldBytes::clone_from_slice(&sig.as_ref()[32..64]);
+                (address, msg_hash, r, s, sig.as_ref()[64] + 27)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(verify_eth_batch(&items), Ok(()));
+        assert_eq!(verify_eth_batch(&[]), Ok(()));
+
+        items[1].0[0] ^= 1; // wrong sender
+        items[2].1[0] ^= 1; // wrong message
+        items[3].4 = 29; // invalid `v`
+        items[5].2 = FieldBytes::default(); // `r = 0`
+        assert_eq!(verify_eth_batch(&items), Err(vec![1, 2, 3, 5]));
+
+        for (i, (address, msg_hash, r, s, v)) in items.iter().enumerate() {
+            assert_eq!(
+                verify_eth(msg_hash, r, s, *v, address).is_ok(),
+                ![1, 2, 3, 5].contains(&i)
+            );
+        }
+    }
+
+    #[test]
+    fn ecdsa_recover() {
+        use super::ecdsa_recover;
+        use crate::ecdsa::VerifyingKey;
+
+        const ORDER: [u8; 32] =
+            hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+        for vector in RECOVERY_TEST_VECTORS {
+            let pk = VerifyingKey::from_sec1_bytes(&vector.pk).unwrap();
+            let z: [u8; 32] = Sha256::digest(vector.msg).into();
+            let r: [u8; 32] = vector.sig[..32].try_into().unwrap();
+            let s: [u8; 32] = vector.sig[32..64].try_into().unwrap();
+            let v = vector.sig[64];
+
+            assert_eq!(ecdsa_recover(&r, &s, v, &z).unwrap(), pk);
+            assert_eq!(ecdsa_recover(&r, &s, v + 27, &z).unwrap(), pk);
+            assert_ne!(ecdsa_recover(&r, &s, v ^ 1, &z).unwrap(), pk);
+
+            for v in [2, 26, 29, 0xff] {
+                assert!(ecdsa_recover(&r, &s, v, &z).is_err());
+            }
+
+            for out_of_range in [[0; 32], ORDER, [0xff; 32]] {
+                assert!(ecdsa_recover(&out_of_range, &s, v, &z).is_err());
+                assert!(ecdsa_recover(&r, &out_of_range, v, &z).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn recover_iter() {
+        use super::{Id, RecoverIter};
+        use crate::ecdsa::VerifyingKey;
+
+        let pk = VerifyingKey::from_sec1_bytes(&RECOVERY_TEST_VECTORS[0].pk).unwrap();
+        let [valid0, valid1] = [0, 1].map(|i| {
+            let vector = &RECOVERY_TEST_VECTORS[i];
+            let sig = Signature::try_from(&vector.sig[..]).unwrap();
+            (sig, <[u8; 32]>::from(Sha256::digest(vector.msg)))
+        });
+
+        // A signature whose `r` is not the `x`-coordinate of any curve point
+        let mut bytes = RECOVERY_TEST_VECTORS[0].sig;
+        bytes[..32].fill(0);
+        let invalid = (1..=u8::MAX)
+            .find_map(|x| {
+                bytes[31] = x;
+                let sig = Signature::try_from(&bytes[..]).unwrap();
+                if bool::from(sig.r_point(Id(bytes[64])).is_none()) {
+                    Some(sig)
+                } else {
+                    None
+                }
+            })
+            .unwrap();
+
+        let items = [
+            valid0,
+            (invalid, [0x42; 32]),
+            valid1,
+            // A valid signature checked against the wrong message hash
+            (valid0.0, [0x42; 32]),
+        ];
+
+        let mut iter = RecoverIter::new(items.into_iter());
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next().unwrap().unwrap(), pk);
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(iter.next().unwrap().unwrap(), pk);
+        assert_ne!(iter.next().unwrap().unwrap(), pk);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn recover_invalid_r() {
+        use super::Id;
+        use crate::ecdsa::P256Error;
+
+        // Neither `1` nor `n + 1` is the `x`-coordinate of a curve point
+        let mut bytes = RECOVERY_TEST_VECTORS[0].sig;
+        bytes[..32].fill(0);
+        bytes[31] = 1;
+
+        for id in 0..=1 {
+            bytes[64] = id;
+            let sig = Signature::try_from(&bytes[..]).unwrap();
+            assert!(bool::from(sig.r_point(Id(id)).is_none()));
+            assert_eq!(
+                sig.recover_verifying_key_from_prehash(&[0x42; 32]),
+                Err(P256Error::PointNotOnCurve)
+            );
+
+            // The reason is kept as the source of the opaque error
+            #[cfg(feature = "std")]
+            {
+                let err = sig
+                    .recover_verifying_key_from_digest_bytes(&[0x42; 32].into())
+                    .unwrap_err();
+                assert_eq!(
+                    std::error::Error::source(&err)
+                        .and_then(|source| source.downcast_ref::<P256Error>()),
+                    Some(&P256Error::PointNotOnCurve)
+                );
+            }
+        }
+
+        // Valid signatures recover the same key through both entry points
+        for vector in RECOVERY_TEST_VECTORS {
+            let sig = Signature::try_from(&vector.sig[..]).unwrap();
+            let msg_hash: [u8; 32] = Sha256::digest(vector.msg).into();
+            assert_eq!(
+                sig.recover_verifying_key_from_prehash(&msg_hash).unwrap(),
+                sig.recover_verifying_key(vector.msg).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn r_point() {
+        use super::Id;
+        use crate::{
+            elliptic_curve::{
+                bigint::U256,
+                ops::{Invert, Reduce},
+                sec1::ToEncodedPoint,
+            },
+            ProjectivePoint, Scalar,
+        };
+
+        for vector in RECOVERY_TEST_VECTORS {
+            let sig = Signature::try_from(&vector.sig[..]).unwrap();
+            let id = sig.recovery_id();
+            let r_point = sig.r_point(id).unwrap();
+
+            assert_eq!(
+                r_point.to_encoded_point(true).as_bytes()[1..],
+                vector.sig[..32]
+            );
+            assert_eq!(u8::from(r_point.is_y_odd()), u8::from(id));
+
+            // `Q = r^-1 (s R - z G)`
+            let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+                Sha256::new_with_prefix(vector.msg).finalize(),
+            );
+            let r_inv = *sig.r().invert();
+            let pk = (ProjectivePoint::from(r_point) * *sig.s() - ProjectivePoint::GENERATOR * z)
+                * r_inv;
+            assert_eq!(
+                pk.to_affine().to_encoded_point(true).as_bytes(),
+                &vector.pk[..]
+            );
+
+            // The other parity yields the negated point
+            let other = sig.r_point(Id::new(u8::from(id) ^ 1).unwrap()).unwrap();
+            assert_eq!(other, -r_point);
+        }
+    }
+
+    #[test]
+    fn recovered_r_point_x() {
+        use crate::{
+            arithmetic::field::FieldElement,
+            elliptic_curve::{bigint::U256, ops::Reduce, AffineXCoordinate},
+            Scalar,
+        };
+
+        for vector in RECOVERY_TEST_VECTORS {
+            let sig = Signature::try_from(&vector.sig[..]).unwrap();
+            let x = sig.recovered_r_point_x().unwrap();
+
+            // Canonical field element, congruent to `r` modulo `n`
+            assert!(bool::from(FieldElement::from_be_bytes(x).is_some()));
+            assert_eq!(<Scalar as Reduce<U256>>::from_be_bytes_reduced(x), *sig.r());
+            assert_eq!(x, sig.r_point(sig.recovery_id()).unwrap().x());
+        }
+
+        // `r = 1` is not the `x`-coordinate of any point
+        let mut bytes = RECOVERY_TEST_VECTORS[0].sig;
+        bytes[..32].fill(0);
+        bytes[31] = 1;
+        let sig = Signature::try_from(&bytes[..]).unwrap();
+        assert!(bool::from(sig.recovered_r_point_x().is_none()));
+    }
+
+    #[test]
+    fn recover_if_in_set() {
+        use ecdsa_core::signature::DigestSigner;
+        use elliptic_curve::rand_core::OsRng;
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let msg_hash: [u8; 32] = Sha256::digest(b"allowlist").into();
+        let signature: Signature = signing_key.sign_digest(Sha256::new_with_prefix(b"allowlist"));
+
+        let mut allowed = [0u8; 3].map(|_| SigningKey::random(&mut OsRng).verifying_key());
+        assert_eq!(signature.recover_if_in_set(&msg_hash, &allowed), None);
+        assert_eq!(signature.recover_if_in_set(&msg_hash, &[]), None);
+
+        allowed[1] = signing_key.verifying_key();
+        assert_eq!(
+            signature.recover_if_in_set(&msg_hash, &allowed),
+            Some(signing_key.verifying_key())
+        );
+
+        // A different message recovers some other key
+        let other_hash: [u8; 32] = Sha256::digest(b"other").into();
+        assert_eq!(signature.recover_if_in_set(&other_hash, &allowed), None);
+    }
+
+    #[cfg(feature = "ecdh")]
+    #[test]
+    fn recover_and_derive() {
+        use crate::{ecdh::diffie_hellman, NonZeroScalar, PublicKey};
+        use ecdsa_core::signature::DigestSigner;
+
+        let signer = SigningKey::from_bytes(&hex!(
+            "519b423d715f8b581f4fa8ee59f4771a5b44c8130b4e3eacca54a56dda72b464"
+        ))
+        .unwrap();
+        let our_secret = NonZeroScalar::try_from(
+            &hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")[..],
+        )
+        .unwrap();
+        let our_public = PublicKey::from_secret_scalar(&our_secret);
+
+        let msg_hash: [u8; 32] = Sha256::digest(b"ephemeral ecdh").into();
+        let signature: Signature = signer.sign_digest(Sha256::new_with_prefix(b"ephemeral ecdh"));
+
+        // Both sides agree on the secret
+        let shared = signature
+            .recover_and_derive(&msg_hash, &our_secret)
+            .unwrap();
+        let expected = diffie_hellman(
+            crate::SecretKey::from(&signer).to_nonzero_scalar(),
+            our_public.as_affine(),
+        );
+        assert_eq!(shared.raw_secret_bytes(), expected.raw_secret_bytes());
+
+        // Any other message recovers a different key, which is rejected
+        let other_hash: [u8; 32] = Sha256::digest(b"other").into();
+        let shared = signature.recover_and_derive(&other_hash, &our_secret);
+        assert!(shared.map_or(true, |shared| shared.raw_secret_bytes()
+            != expected.raw_secret_bytes()));
+
+        let mut bytes = [0u8; 65];
+        bytes.copy_from_slice(signature.as_ref());
+        bytes[..32].fill(0);
+        bytes[31] = 1;
+        let invalid = Signature::try_from(&bytes[..]).unwrap();
+        assert!(invalid.recover_and_derive(&msg_hash, &our_secret).is_err());
+    }
+
+    #[test]
+    fn upstream_conversion() {
+        use super::Id;
+        use elliptic_curve::rand_core::OsRng;
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let sig: Signature = signing_key.sign(b"upstream");
+        let (signature, recovery_id) = sig.into();
+
+        // High-`s` twin with the other recovery ID
+        let high_s = Signature::new(
+            &crate::ecdsa::Signature::from_scalars(sig.r().to_bytes(), (-*sig.s()).to_bytes())
+                .unwrap(),
+            Id(sig.recovery_id().0 ^ 1),
+        )
+        .unwrap();
+
+        for sig in [sig, high_s] {
+            let parts: (crate::ecdsa::Signature, ecdsa_core::RecoveryId) = sig.into();
+            assert_eq!(Signature::try_from(parts).unwrap().as_ref(), sig.as_ref());
+        }
+
+        let x_reduced = ecdsa_core::RecoveryId::new(recovery_id.is_y_odd(), true);
+        assert!(Signature::try_from((signature, x_reduced)).is_err());
+    }
+
     #[test]
     fn public_key_recovery() {
         for vector in RECOVERY_TEST_VECTORS {
@@ -379,4 +1395,49 @@ mod tests {
         let sig: Signature = signing_key.sign(&msg);
         assert_eq!(sig.as_ref(), &hex!("5da79ba879954cc8a25e8e48eae031c856adb2d385cdc6ac75c890fff62df5f33938d7da87e3175d9d00e90671b43a6c7e9327ac5be03ed8f6b195cc44a8089d00"));
     }
+
+    proptest! {
+        /// Mirrors the `recoverable_signature` fuzz target.
+        #[test]
+        fn parse_never_panics(
+            r in prop::array::uniform32(any::<u8>()),
+            s in prop::array::uniform32(any::<u8>()),
+            recovery_id in 0u8..4,
+        ) {
+            let mut bytes = [0u8; 65];
+            bytes[..32].copy_from_slice(&r);
+            bytes[32..64].copy_from_slice(&s);
+            bytes[64] = recovery_id;
+
+            let _ = Signature::from_bytes_strict(&bytes);
+
+            if let Ok(sig) = Signature::try_from(&bytes[..]) {
+                assert_eq!(u8::from(sig.recovery_id()), recovery_id);
+                assert_eq!(sig.r().to_bytes().as_slice(), &r[..]);
+                assert_eq!(sig.s().to_bytes().as_slice(), &s[..]);
+                let _ = crate::ecdsa::Signature::from(sig);
+                let _ = sig.recover_verifying_key(b"proptest");
+            }
+        }
+
+        /// Converting to the upstream types and back is lossless
+        #[test]
+        fn upstream_round_trip(
+            r in prop::array::uniform32(any::<u8>()),
+            s in prop::array::uniform32(any::<u8>()),
+            recovery_id in 0u8..2,
+        ) {
+            let mut bytes = [0u8; 65];
+            bytes[..32].copy_from_slice(&r);
+            bytes[32..64].copy_from_slice(&s);
+            bytes[64] = recovery_id;
+
+            if let Ok(sig) = Signature::try_from(&bytes[..]) {
+                let parts = <(crate::ecdsa::Signature, ecdsa_core::RecoveryId)>::from(sig);
+                assert_eq!(parts.0.as_ref(), &bytes[..64]);
+                assert_eq!(parts.1.to_byte(), recovery_id);
+                assert_eq!(Signature::try_from(parts).unwrap().as_ref(), &bytes[..]);
+            }
+        }
+    }
 }
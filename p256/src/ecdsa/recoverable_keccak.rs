@@ -0,0 +1,180 @@
+//! Recoverable signatures which use Keccak-256 as their message digest.
+//!
+//! [`PrehashSignature`] ties a signature type to a single digest, and
+//! [`recoverable::Signature`] is tied to SHA-256. This module provides a
+//! distinct [`Signature`] type with the same encoding whose digest is
+//! Keccak-256, so the generic [`Signer`] and [`Verifier`] traits hash
+//! messages the way Ethereum does.
+//!
+//! ```
+//! # #[cfg(all(feature = "ecdsa", feature = "keccak256"))]
+//! # {
+//! use p256::ecdsa::{
+//!     recoverable_keccak,
+//!     signature::{Signer, Verifier},
+//!     SigningKey,
+//! };
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! let signing_key = SigningKey::random(&mut OsRng);
+//! let message = b"ECDSA proves knowledge of a secret number in the context of a single message";
+//!
+//! let signature: recoverable_keccak::Signature = signing_key.sign(message);
+//! assert!(signing_key.verifying_key().verify(message, &signature).is_ok());
+//! assert_eq!(
+//!     signature.recover_verifying_key(message).unwrap(),
+//!     signing_key.verifying_key()
+//! );
+//! # }
+//! ```
+//!
+//! [`Signer`]: super::signature::Signer
+//! [`Verifier`]: super::signature::Verifier
+
+use super::recoverable;
+use core::fmt::{self, Debug};
+use ecdsa_core::{
+    signature::{self, PrehashSignature},
+    Error, Result,
+};
+use sha3::Keccak256;
+
+#[cfg(feature = "ecdsa")]
+use {
+    super::VerifyingKey,
+    ecdsa_core::signature::digest::{Digest, FixedOutput},
+    elliptic_curve::consts::U32,
+};
+
+/// Size of a Keccak-256 recoverable signature in bytes.
+pub const SIZE: usize = recoverable::SIZE;
+
+/// Recoverable signature over a Keccak-256 message digest.
+///
+/// This has the same encoding as (and converts to and from)
+/// [`recoverable::Signature`]; only the associated digest differs.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct Signature(recoverable::Signature);
+
+impl Signature {
+    /// Get the recovery [`Id`][`recoverable::Id`] for this signature.
+    pub fn recovery_id(self) -> recoverable::Id {
+        self.0.recovery_id()
+    }
+
+    /// Recover the [`VerifyingKey`] used to create this signature over the
+    /// given message, hashing it with Keccak-256.
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn recover_verifying_key(&self, msg: &[u8]) -> Result<VerifyingKey> {
+        self.recover_verifying_key_from_digest(Keccak256::new_with_prefix(msg))
+    }
+
+    /// Recover the [`VerifyingKey`] used to create this signature from the
+    /// provided precomputed [`Digest`].
+    #[cfg(feature = "ecdsa")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+    pub fn recover_verifying_key_from_digest<D>(&self, msg_digest: D) -> Result<VerifyingKey>
+    where
+        D: Digest + FixedOutput<OutputSize = U32>,
+    {
+        self.0
+            .recover_verifying_key_from_digest_bytes(&msg_digest.finalize_fixed())
+    }
+}
+
+impl signature::Signature for Signature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bytes.try_into()
+    }
+}
+
+impl PrehashSignature for Signature {
+    type Digest = Keccak256;
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "KeccakRecoverableSignature {{ bytes: {:?} }}",
+            self.as_ref()
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        recoverable::Signature::try_from(bytes).map(Self)
+    }
+}
+
+impl From<recoverable::Signature> for Signature {
+    fn from(signature: recoverable::Signature) -> Self {
+        Self(signature)
+    }
+}
+
+impl From<Signature> for recoverable::Signature {
+    fn from(signature: Signature) -> Self {
+        signature.0
+    }
+}
+
+impl From<Signature> for super::Signature {
+    fn from(signature: Signature) -> Self {
+        signature.0.into()
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa"))]
+mod tests {
+    use super::Signature;
+    use crate::ecdsa::{
+        recoverable,
+        signature::{DigestSigner, DigestVerifier, Signer, Verifier},
+        SigningKey,
+    };
+    use elliptic_curve::rand_core::OsRng;
+    use sha3::{Digest, Keccak256};
+
+    #[test]
+    fn sign_and_verify() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = signing_key.verifying_key();
+        let msg = b"keccak";
+
+        let signature: Signature = signing_key.sign(msg);
+        assert!(verifying_key.verify(msg, &signature).is_ok());
+        assert!(verifying_key.verify(b"other", &signature).is_err());
+        assert_eq!(signature.recover_verifying_key(msg).unwrap(), verifying_key);
+
+        // Same as signing the Keccak-256 digest explicitly
+        let digest = Keccak256::new_with_prefix(msg);
+        let explicit: recoverable::Signature = signing_key.sign_digest(digest.clone());
+        assert_eq!(recoverable::Signature::from(signature), explicit);
+        assert!(verifying_key.verify_digest(digest, &explicit).is_ok());
+
+        // ...and different from the SHA-256 flavor
+        let sha256: recoverable::Signature = signing_key.sign(msg);
+        assert_ne!(recoverable::Signature::from(signature), sha256);
+        assert!(verifying_key.verify(msg, &Signature::from(sha256)).is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let signature: Signature = SigningKey::random(&mut OsRng).sign(b"keccak");
+        let bytes = signature.as_ref();
+        assert_eq!(bytes.len(), super::SIZE);
+        assert_eq!(Signature::try_from(bytes).unwrap(), signature);
+        assert!(Signature::try_from(&bytes[..64]).is_err());
+    }
+}
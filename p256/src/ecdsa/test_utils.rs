@@ -0,0 +1,161 @@
+//! Deterministic generation of ECDSA/P-256 test vectors, e.g. for
+//! cross-testing against other implementations.
+//!
+//! The same seed always produces the same vectors.
+
+use super::{recoverable, signature::Signer, Error, Signature, SigningKey, VerifyingKey};
+use crate::{NonZeroScalar, Scalar};
+use alloc::vec::Vec;
+use ecdsa_core::hazmat::SignPrimitive;
+use elliptic_curve::PrimeField;
+use sha2::Sha256;
+
+/// Personalization string of the DRBG used to derive test vectors.
+const PERSONALIZATION: &[u8] = b"p256 ecdsa test vectors";
+
+/// Generated ECDSA/P-256 test vector.
+///
+/// The signature is computed over the SHA-256 digest of `msg` using an
+/// RFC6979 nonce, i.e. exactly as [`Signer`] does for
+/// [`recoverable::Signature`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TestVector {
+    /// Secret scalar (big endian)
+    pub secret_key: [u8; 32],
+
+    /// Public key (compressed SEC1 encoding)
+    pub public_key: [u8; 33],
+
+    /// Message
+    pub msg: [u8; 32],
+
+    /// Signature (`r || s`, both big endian)
+    pub signature: [u8; 64],
+
+    /// Recovery ID of the signature
+    pub recovery_id: u8,
+}
+
+/// Generate `count` test vectors from the given seed.
+///
+/// Keys and messages are drawn from HMAC-DRBG-SHA-256 seeded with `seed`, so
+/// the output is reproducible.
+pub fn generate_test_vectors(seed: &[u8], count: usize) -> Vec<TestVector> {
+    let mut drbg = rfc6979::HmacDrbg::<Sha256>::new(seed, &[], PERSONALIZATION);
+
+    (0..count)
+        .map(|_| {
+            let signing_key = loop {
+                let mut secret_key = [0u8; 32];
+                drbg.fill_bytes(&mut secret_key);
+
+                if let Ok(signing_key) = SigningKey::from_bytes(&secret_key) {
+                    break signing_key;
+                }
+            };
+
+            let mut msg = [0u8; 32];
+            drbg.fill_bytes(&mut msg);
+
+            let signature: recoverable::Signature = signing_key.sign(&msg);
+            let mut signature_bytes = [0u8; 64];
+            signature_bytes.copy_from_slice(&signature.as_ref()[..64]);
+
+            let mut public_key = [0u8; 33];
+            public_key.copy_from_slice(VerifyingKey::from(&signing_key).to_bytes().as_ref());
+
+            TestVector {
+                secret_key: signing_key.to_bytes().into(),
+                public_key,
+                msg,
+                signature: signature_bytes,
+                recovery_id: signature.recovery_id().into(),
+            }
+        })
+        .collect()
+}
+
+/// Compute an ECDSA signature over the (already reduced) message digest `z`
+/// with the secret scalar `d`, using the explicitly supplied nonce `k`.
+///
+/// # ⚠️ Warning
+///
+/// This is only intended for known-answer tests with a fixed `k`, e.g. the
+/// FIPS 186 `SigGen` vectors. **Never** use it to produce real signatures:
+/// reusing `k`, or choosing it in any way that isn't uniformly random or
+/// derived per RFC6979, reveals the secret key.
+///
+/// The returned signature is not normalized to low-`s` form.
+pub fn sign_prehashed_with_nonce(
+    d: &NonZeroScalar,
+    k: &NonZeroScalar,
+    z: &Scalar,
+) -> Result<(Signature, recoverable::Id), Error> {
+    let (signature, recovery_id) = d.as_ref().try_sign_prehashed(**k, z.to_repr())?;
+    let recovery_id = recovery_id.ok_or_else(Error::new)?.try_into()?;
+    Ok((signature, recovery_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_test_vectors, sign_prehashed_with_nonce};
+    use crate::{
+        ecdsa::{recoverable, signature::Verifier, Signature, SigningKey, VerifyingKey},
+        test_vectors::ecdsa::ECDSA_TEST_VECTORS,
+        NonZeroScalar, Scalar,
+    };
+    use elliptic_curve::{bigint::U256, generic_array::GenericArray, ops::Reduce};
+
+    #[test]
+    fn reproducible() {
+        let vectors = generate_test_vectors(b"seed", 16);
+        assert_eq!(vectors.len(), 16);
+        assert_eq!(generate_test_vectors(b"seed", 16), vectors);
+        assert_eq!(generate_test_vectors(b"seed", 4), vectors[..4]);
+        assert_ne!(generate_test_vectors(b"other seed", 16), vectors);
+        assert!(generate_test_vectors(b"seed", 0).is_empty());
+    }
+
+    #[test]
+    fn valid() {
+        let vectors = generate_test_vectors(b"seed", 16);
+
+        for (i, vector) in vectors.iter().enumerate() {
+            assert!(vectors[..i]
+                .iter()
+                .all(|other| other.secret_key != vector.secret_key));
+
+            let verifying_key = VerifyingKey::from_sec1_bytes(&vector.public_key).unwrap();
+            let signature = Signature::try_from(&vector.signature[..]).unwrap();
+            assert!(verifying_key.verify(&vector.msg, &signature).is_ok());
+
+            let recovery_id = recoverable::Id::new(vector.recovery_id).unwrap();
+            let recovered = recoverable::Signature::new(&signature, recovery_id)
+                .unwrap()
+                .recover_verifying_key(&vector.msg)
+                .unwrap();
+            assert_eq!(recovered, verifying_key);
+        }
+    }
+
+    #[test]
+    fn fips_186_fixed_nonce() {
+        for vector in ECDSA_TEST_VECTORS {
+            let d = NonZeroScalar::try_from(vector.d).unwrap();
+            let k = NonZeroScalar::try_from(vector.k).unwrap();
+            let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(
+                GenericArray::clone_from_slice(vector.m),
+            );
+
+            let (signature, recovery_id) = sign_prehashed_with_nonce(&d, &k, &z).unwrap();
+            assert_eq!(signature.r().to_bytes().as_slice(), vector.r);
+            assert_eq!(signature.s().to_bytes().as_slice(), vector.s);
+
+            let recovered = recoverable::Signature::new(&signature, recovery_id)
+                .unwrap()
+                .recover_verifying_key_from_digest_bytes(&z.to_bytes())
+                .unwrap();
+            assert_eq!(recovered, SigningKey::from(d).verifying_key());
+        }
+    }
+}
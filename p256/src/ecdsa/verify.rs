@@ -1,34 +1,97 @@
 //! ECDSA verification support.
 
-use super::{recoverable, Error, Signature};
+use super::{der, recoverable, Error, P256Error, Signature};
+
+#[cfg(feature = "keccak256")]
+use super::recoverable_keccak;
 use crate::{
-    AffinePoint, CompressedPoint, EncodedPoint, FieldBytes, NistP256, ProjectivePoint, PublicKey,
-    Scalar,
+    arithmetic::field::FieldElement, AffinePoint, CompressedPoint, EncodedPoint, FieldBytes,
+    NistP256, NonZeroScalar, PrecomputedPoint, ProjectivePoint, PublicKey, Scalar,
 };
+use core::ops::Neg;
 use ecdsa_core::{hazmat::VerifyPrimitive, signature};
 use elliptic_curve::{
     bigint::U256,
-    consts::U32,
+    ff::Field,
+    generic_array::GenericArray,
     ops::{Invert, LinearCombination, Reduce},
-    sec1::ToEncodedPoint,
+    sec1::{Coordinates, ToEncodedPoint},
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
     AffineXCoordinate,
 };
 use signature::digest::{Digest, FixedOutput};
 use signature::DigestVerifier;
 
 #[cfg(feature = "sha256")]
-use signature::PrehashSignature;
+use {sha2::Sha256, signature::PrehashSignature};
 
 #[cfg(feature = "pkcs8")]
 use crate::pkcs8::{self, DecodePublicKey};
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "pem")]
 use core::str::FromStr;
 
-#[cfg(all(feature = "pem", feature = "serde"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "serde"))))]
+#[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
+/// Policy controlling which legal-but-discouraged signatures are accepted by
+/// [`VerifyingKey::verify_with_policy`].
+///
+/// The [`Default`] policy matches [`VerifyingKey::verify`]: DER encodings
+/// must be minimal, but high-`s` signatures are accepted. Consensus-critical
+/// code will typically want [`VerificationPolicy::STRICT`].
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VerificationPolicy {
+    /// Reject signatures whose `s` value is greater than `n / 2`.
+    pub reject_high_s: bool,
+
+    /// Reject DER encodings with unnecessary leading zeros on `r` or `s`.
+    ///
+    /// When `false`, signatures are parsed with [`der::from_der`].
+    pub reject_non_minimal_der: bool,
+
+    /// Reject signatures over a message whose digest reduces to zero modulo
+    /// the group order.
+    ///
+    /// With `z = 0` verification only checks `R = (r / s) Q`, so anyone can
+    /// produce a valid signature for any key without knowing its secret:
+    /// pick a random `t`, let `R = t Q`, `r = x(R)` and `s = r / t`. An
+    /// honest hash output practically never reduces to zero, so seeing one
+    /// means the digest was most likely chosen by an attacker, e.g. in
+    /// protocols which verify caller-supplied prehashes.
+    pub reject_zero_digest: bool,
+}
+
+impl VerificationPolicy {
+    /// Accept every signature which can be unambiguously decoded.
+    pub const LENIENT: Self = Self {
+        reject_high_s: false,
+        reject_non_minimal_der: false,
+        reject_zero_digest: false,
+    };
+
+    /// Accept only canonical signatures.
+    pub const STRICT: Self = Self {
+        reject_high_s: true,
+        reject_non_minimal_der: true,
+        reject_zero_digest: true,
+    };
+}
+
+impl Default for VerificationPolicy {
+    fn default() -> Self {
+        Self {
+            reject_high_s: false,
+            reject_non_minimal_der: true,
+            reject_zero_digest: false,
+        }
+    }
+}
+
 /// ECDSA/P-256 verification key (i.e. public key)
 ///
 /// # `serde` support
@@ -36,8 +99,11 @@ use serdect::serde::{de, ser, Deserialize, Serialize};
 /// When the `serde` feature of this crate is enabled, the `Serialize` and
 /// `Deserialize` traits are impl'd for this type.
 ///
-/// The serialization is binary-oriented and supports ASN.1 DER-encoded
-/// X.509 Subject Public Key Info (SPKI) as the encoding format.
+/// Binary formats use the 33-byte compressed SEC1 encoding as a fixed-size
+/// array with no length prefix, e.g. `postcard` emits exactly 33 bytes.
+/// Text formats use a hex-encoded ASN.1 DER X.509 Subject Public Key Info
+/// (SPKI) document with an uncompressed point, regardless of which other
+/// features are enabled.
 ///
 /// For a more text-friendly encoding of public keys, use
 /// [`elliptic_curve::JwkEcKey`] instead.
@@ -54,6 +120,35 @@ impl VerifyingKey {
         ecdsa_core::VerifyingKey::from_sec1_bytes(bytes).map(|key| VerifyingKey { inner: key })
     }
 
+    /// Initialize [`VerifyingKey`] from a SEC1-encoded public key, reporting
+    /// why the input was rejected:
+    ///
+    /// - [`P256Error::InvalidEncoding`] if the tag or length is wrong
+    /// - [`P256Error::Identity`] for the identity encoding
+    /// - [`P256Error::CoordinateOutOfRange`] if a coordinate is `p` or more
+    /// - [`P256Error::PointNotOnCurve`] if there is no point with this `x`
+    ///   coordinate or, for uncompressed encodings, `y` doesn't match it
+    ///
+    /// Accepts the same inputs as [`VerifyingKey::from_sec1_bytes`].
+    pub fn from_sec1_bytes_strict(bytes: &[u8]) -> Result<Self, P256Error> {
+        let encoded_point =
+            EncodedPoint::from_bytes(bytes).map_err(|_| P256Error::InvalidEncoding)?;
+
+        let (x, y) = match encoded_point.coordinates() {
+            Coordinates::Identity => return Err(P256Error::Identity),
+            Coordinates::Compact { x } | Coordinates::Compressed { x, .. } => (x, None),
+            Coordinates::Uncompressed { x, y } => (x, Some(y)),
+        };
+
+        for coordinate in core::iter::once(x).chain(y) {
+            if FieldElement::from_sec1(*coordinate).is_none().into() {
+                return Err(P256Error::CoordinateOutOfRange);
+            }
+        }
+
+        Self::from_encoded_point(&encoded_point).map_err(|_| P256Error::PointNotOnCurve)
+    }
+
     /// Initialize [`VerifyingKey`] from a SEC1 [`EncodedPoint`].
     // TODO(tarcieri): switch to using `FromEncodedPoint` trait?
     pub fn from_encoded_point(public_key: &EncodedPoint) -> Result<Self, Error> {
@@ -66,6 +161,301 @@ impl VerifyingKey {
     pub fn to_bytes(&self) -> CompressedPoint {
         CompressedPoint::clone_from_slice(EncodedPoint::from(self).as_bytes())
     }
+
+    /// Returns the big endian affine coordinates `(x, y)` of this key.
+    pub fn coordinates(&self) -> ([u8; 32], [u8; 32]) {
+        let (x, y) = PublicKey::from(self)
+            .as_affine()
+            .coordinates()
+            .expect("verifying key is never the identity");
+
+        (x.into(), y.into())
+    }
+
+    /// Serialize this [`VerifyingKey`] as a compressed SEC1 point, formatting
+    /// the tag and `x`-coordinate directly.
+    ///
+    /// This is equivalent to [`VerifyingKey::to_bytes`], but relies on the
+    /// key never being the identity instead of checking for it, and skips
+    /// the intermediate [`EncodedPoint`]. Intended for high-throughput
+    /// export of freshly generated keys.
+    pub fn to_compressed_unchecked(&self) -> [u8; 33] {
+        let point = PublicKey::from(self);
+        let point = point.as_affine();
+
+        let mut bytes = [0u8; 33];
+        bytes[0] = 0x02 | u8::from(point.is_y_odd());
+        bytes[1..].copy_from_slice(&point.x());
+        bytes
+    }
+
+    /// Get the structured `SubjectPublicKeyInfo` for this key.
+    #[cfg(feature = "pkcs8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+    pub fn to_spki(&self) -> SubjectPublicKeyInfoOwned {
+        self.into()
+    }
+
+    /// Verify the given message against a signature using SHA-256, rejecting
+    /// high-`s` signatures and reporting why verification failed.
+    #[cfg(feature = "sha256")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha256")))]
+    pub fn verify_strict(&self, msg: &[u8], signature: &Signature) -> Result<(), P256Error> {
+        if signature.normalize_s().is_some() {
+            return Err(P256Error::Malleable);
+        }
+
+        signature::Verifier::verify(self, msg, signature).map_err(|_| P256Error::VerificationFailed)
+    }
+
+    /// Verify the given message digest against a signature whose `r` and
+    /// `s` components are provided separately (as big endian integers).
+    ///
+    /// Returns [`P256Error::InvalidR`] or [`P256Error::InvalidS`] if the
+    /// respective component is zero or not less than the group order.
+    pub fn verify_digest_rs<D>(
+        &self,
+        digest: D,
+        r: &[u8; 32],
+        s: &[u8; 32],
+    ) -> Result<(), P256Error>
+    where
+        D: Digest + FixedOutput,
+    {
+        let (r, s) = check_scalars(&FieldBytes::from(*r), &FieldBytes::from(*s))?;
+        let signature = Signature::from_scalars(r, s).map_err(|_| P256Error::InvalidEncoding)?;
+        self.verify_digest(digest, &signature)
+            .map_err(|_| P256Error::VerificationFailed)
+    }
+
+    /// Verify the given message against an ASN.1 DER-encoded signature using
+    /// SHA-256, subject to the given [`VerificationPolicy`].
+    #[cfg(feature = "sha256")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha256")))]
+    pub fn verify_with_policy(
+        &self,
+        msg: &[u8],
+        signature: &[u8],
+        policy: VerificationPolicy,
+    ) -> Result<(), P256Error> {
+        self.verify_prehash_with_policy(&Sha256::digest(msg).into(), signature, policy)
+    }
+
+    /// Verify the given 32-byte message digest against an ASN.1 DER-encoded
+    /// signature, subject to the given [`VerificationPolicy`].
+    pub fn verify_prehash_with_policy(
+        &self,
+        prehash: &[u8; 32],
+        signature: &[u8],
+        policy: VerificationPolicy,
+    ) -> Result<(), P256Error> {
+        let signature = if policy.reject_non_minimal_der {
+            der::from_der_strict(signature)?
+        } else {
+            der::from_der(signature)?
+        };
+
+        if policy.reject_high_s && signature.normalize_s().is_some() {
+            return Err(P256Error::Malleable);
+        }
+
+        let z = FieldBytes::from(*prehash);
+
+        if policy.reject_zero_digest
+            && bool::from(<Scalar as Reduce<U256>>::from_be_bytes_reduced(z).is_zero())
+        {
+            return Err(P256Error::ZeroDigest);
+        }
+
+        PublicKey::from(self)
+            .as_affine()
+            .verify_prehashed(z, &signature)
+            .map_err(|_| P256Error::VerificationFailed)
+    }
+
+    /// Compute a fingerprint of this [`VerifyingKey`] by hashing its
+    /// canonical SEC1 encoding (with point compression applied) using the
+    /// given [`Digest`].
+    ///
+    /// The fingerprint is independent of the encoding the key was originally
+    /// parsed from.
+    pub fn fingerprint<D: Digest>(&self) -> GenericArray<u8, D::OutputSize> {
+        D::digest(self.to_bytes())
+    }
+
+    /// Compute the SHA-256 fingerprint of this [`VerifyingKey`].
+    ///
+    /// See [`VerifyingKey::fingerprint`] for more information.
+    #[cfg(feature = "sha256")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha256")))]
+    pub fn fingerprint_sha256(&self) -> [u8; 32] {
+        self.fingerprint::<Sha256>().into()
+    }
+
+    /// Returns the negation `-Q` of this key's point `Q`, i.e. the same
+    /// `x`-coordinate with the opposite `y`.
+    ///
+    /// This negates the affine point directly rather than going through its
+    /// SEC1 encoding.
+    pub fn negate(&self) -> VerifyingKey {
+        VerifyingKey::try_from(-*PublicKey::from(self).as_affine())
+            .expect("negation of a valid key is never the identity")
+    }
+
+    /// Check in constant time whether this key's point is the negation of
+    /// `other`'s, i.e. whether `self == -other`.
+    ///
+    /// Since a valid key never has `y = 0`, this holds exactly when both
+    /// compressed SEC1 encodings have the same `x`-coordinate but different
+    /// tags (i.e. opposite `y` parity), which avoids computing a negation.
+    pub fn is_negative_of(&self, other: &VerifyingKey) -> Choice {
+        let (a, b) = (self.to_bytes(), other.to_bytes());
+        let (a, b) = (a.as_slice(), b.as_slice());
+        a[1..].ct_eq(&b[1..]) & !a[0].ct_eq(&b[0])
+    }
+
+    /// Check whether the given SEC1 encoding (compressed or uncompressed) is
+    /// of the same point as this key, regardless of how this key was itself
+    /// encoded.
+    ///
+    /// Returns `false` if the bytes aren't a valid public key.
+    pub fn encodes_same_point(&self, sec1_bytes: &[u8]) -> bool {
+        Self::from_sec1_bytes(sec1_bytes).map_or(false, |other| other == *self)
+    }
+
+    /// Verify the prehashed message `z` against the provided signature
+    /// without branching on any intermediate value, returning whether it is
+    /// valid as a [`Choice`].
+    ///
+    /// Verification normally only handles public data, which is why
+    /// [`DigestVerifier`] and friends are free to use faster variable-time
+    /// code paths (e.g. [`PrecomputedVerifyingKey`]) and return as soon as
+    /// the outcome is known. Use this instead when the message (or whether
+    /// the signature is valid) must stay secret, e.g. when checking a
+    /// signature over a secret-dependent value inside a larger
+    /// constant-time computation.
+    ///
+    /// Both scalar multiplications, the field and scalar inversions and the
+    /// final comparison run in constant time. Branching on the returned
+    /// [`Choice`] reveals the result, and nothing else.
+    pub fn verify_prehashed_ct(&self, z: &FieldBytes, signature: &Signature) -> Choice {
+        let x = self.compute_r(z, signature).x();
+        <Scalar as Reduce<U256>>::from_be_bytes_reduced(x).ct_eq(&signature.r())
+    }
+
+    /// Verify the prehashed message `z` against the provided signature,
+    /// returning the computed `x(u1 G + u2 Q) mod n` along with whether it
+    /// equals `r`, i.e. whether the signature is valid.
+    ///
+    /// The computed value is returned for invalid signatures too, so callers
+    /// guarding against fault injection can compare it against `r` again
+    /// themselves, or against the result of an independent verifier, rather
+    /// than trusting a single boolean. The two scalar multiplications are
+    /// computed separately, i.e. not with the
+    /// [`LinearCombination`] used by [`VerifyPrimitive::verify_prehashed`],
+    /// so a fault in either path is unlikely to affect both.
+    ///
+    /// Fails only if `u1 G + u2 Q` is the identity, which has no
+    /// x-coordinate.
+    pub fn verify_returning_computed_r(
+        &self,
+        z: &FieldBytes,
+        signature: &Signature,
+    ) -> Result<(Scalar, bool), Error> {
+        let point = self.compute_r(z, signature);
+
+        if point.is_identity().into() {
+            return Err(Error::new());
+        }
+
+        let computed_r = <Scalar as Reduce<U256>>::from_be_bytes_reduced(point.x());
+        Ok((computed_r, computed_r == *signature.r()))
+    }
+
+    /// Verify the given message digest against a signature and, on success,
+    /// return it as a [`recoverable::Signature`].
+    ///
+    /// The recovery ID is taken from the point `R = u1 G + u2 Q` computed
+    /// during verification, so this costs no more than [`DigestVerifier`],
+    /// whereas verifying and then using
+    /// [`recoverable::Signature::from_digest_trial_recovery`] repeats the
+    /// scalar multiplications. `s` is *not* normalized.
+    ///
+    /// Fails if the signature is invalid, or in the (cryptographically
+    /// negligible) case where the x-coordinate of `R` is at least `n`, which
+    /// [`recoverable::Id`] can't express.
+    pub fn verify_digest_recovering<D>(
+        &self,
+        digest: D,
+        signature: &Signature,
+    ) -> Result<recoverable::Signature, Error>
+    where
+        D: Digest + FixedOutput,
+    {
+        let point = self.compute_r(&bits2field(&digest.finalize_fixed()), signature);
+
+        // Comparing the unreduced x-coordinate also rules out `x >= n`
+        if point.is_identity().into() || point.x() != signature.r().to_bytes() {
+            return Err(Error::new());
+        }
+
+        let recovery_id = recoverable::Id::new(point.is_y_odd().into())?;
+        recoverable::Signature::new(signature, recovery_id)
+    }
+
+    /// Compute the point `R = u1 G + u2 Q` for the prehashed message `z`,
+    /// where `u1 = z / s` and `u2 = r / s`, which a valid signature's `r` is
+    /// the x-coordinate of.
+    ///
+    /// Both scalar multiplications run in constant time, and are computed
+    /// separately rather than as a [`LinearCombination`].
+    fn compute_r(&self, z: &FieldBytes, signature: &Signature) -> AffinePoint {
+        let (r, s) = signature.split_scalars();
+        let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(*z);
+
+        let s_inv = *s.invert();
+        let u1 = z * s_inv;
+        let u2 = *r * s_inv;
+
+        (ProjectivePoint::GENERATOR * u1 + ProjectivePoint::from(self) * u2).to_affine()
+    }
+
+    /// Derive the key `P + tweak * G`, which is the public key of
+    /// [`SigningKey::add_tweak`][`super::SigningKey::add_tweak`] applied to
+    /// the corresponding signing key.
+    ///
+    /// Returns an error if the result is the identity.
+    pub fn add_tweak(&self, tweak: &Scalar) -> Result<VerifyingKey, Error> {
+        VerifyingKey::try_from(ProjectivePoint::from(self) + ProjectivePoint::GENERATOR * tweak)
+    }
+
+    /// Derive the key `tweak * P`, which is the public key of
+    /// [`SigningKey::mul_tweak`][`super::SigningKey::mul_tweak`] applied to
+    /// the corresponding signing key.
+    ///
+    /// The group has prime order, so multiplying a valid key by a non-zero
+    /// scalar never yields the identity.
+    pub fn mul_tweak(&self, tweak: &NonZeroScalar) -> VerifyingKey {
+        VerifyingKey::try_from(ProjectivePoint::from(self) * **tweak)
+            .expect("non-zero multiple of a valid key is never the identity")
+    }
+
+    /// Precompute a window table for this key, speeding up the verification
+    /// of many signatures produced by the same signer.
+    pub fn precompute(&self) -> PrecomputedVerifyingKey {
+        PrecomputedVerifyingKey::from(self)
+    }
+
+    /// Hash a message `prefix` shared by many messages once, returning a
+    /// [`PrefixVerifier`] which only needs to hash each message's suffix.
+    #[cfg(feature = "sha256")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sha256")))]
+    pub fn verifier_with_prefix(&self, prefix: &[u8]) -> PrefixVerifier {
+        PrefixVerifier {
+            verifying_key: *self,
+            digest: Sha256::new_with_prefix(prefix),
+        }
+    }
 }
 
 #[cfg(feature = "sha256")]
@@ -79,25 +469,63 @@ where
     }
 }
 
+/// Digests of any size are accepted and converted to the message
+/// representative as described in FIPS 186-4 § 6.4: longer digests are
+/// truncated to their leftmost 256 bits and shorter ones are zero-extended.
 impl<D> DigestVerifier<D, Signature> for VerifyingKey
 where
-    D: Digest + FixedOutput<OutputSize = U32>,
+    D: Digest + FixedOutput,
 {
     fn verify_digest(&self, digest: D, signature: &Signature) -> Result<(), Error> {
-        self.inner.verify_digest(digest, signature)
+        PublicKey::from(self)
+            .as_affine()
+            .verify_prehashed(bits2field(&digest.finalize_fixed()), signature)
     }
 }
 
 impl<D> DigestVerifier<D, recoverable::Signature> for VerifyingKey
 where
-    D: Digest + FixedOutput<OutputSize = U32>,
+    D: Digest + FixedOutput,
 {
     fn verify_digest(&self, digest: D, signature: &recoverable::Signature) -> Result<(), Error> {
-        self.inner
-            .verify_digest(digest, &Signature::from(*signature))
+        self.verify_digest(digest, &Signature::from(*signature))
     }
 }
 
+#[cfg(feature = "keccak256")]
+#[cfg_attr(docsrs, doc(cfg(feature = "keccak256")))]
+impl<D> DigestVerifier<D, recoverable_keccak::Signature> for VerifyingKey
+where
+    D: Digest + FixedOutput,
+{
+    fn verify_digest(
+        &self,
+        digest: D,
+        signature: &recoverable_keccak::Signature,
+    ) -> Result<(), Error> {
+        self.verify_digest(digest, &Signature::from(*signature))
+    }
+}
+
+/// Convert a message digest of any size into the message representative
+/// (FIPS 186-4 § 6.4): the leftmost 256 bits of the digest, interpreted as a
+/// big endian integer.
+///
+/// Every supported digest has a whole number of bytes, so longer digests are
+/// simply truncated and shorter ones are zero-extended on the left.
+fn bits2field(digest: &[u8]) -> FieldBytes {
+    let mut field_bytes = FieldBytes::default();
+    let size = field_bytes.len();
+
+    if digest.len() >= size {
+        field_bytes.copy_from_slice(&digest[..size]);
+    } else {
+        field_bytes[(size - digest.len())..].copy_from_slice(digest);
+    }
+
+    field_bytes
+}
+
 impl VerifyPrimitive<NistP256> for AffinePoint {
     fn verify_prehashed(&self, z: FieldBytes, signature: &Signature) -> Result<(), Error> {
         let (r, s) = signature.split_scalars();
@@ -124,6 +552,332 @@ impl VerifyPrimitive<NistP256> for AffinePoint {
     }
 }
 
+/// ECDSA/P-256 verification key along with a precomputed window table for
+/// the public point.
+///
+/// Verifying a signature with a [`VerifyingKey`] rebuilds this table every
+/// time. When many signatures from the same signer need to be checked, build
+/// it once with [`VerifyingKey::precompute`] instead. Both scalar
+/// multiplications are also computed together in variable time, which is
+/// safe since verification only involves public values.
+///
+/// Results are identical to those of [`VerifyingKey`].
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+#[derive(Copy, Clone, Debug)]
+pub struct PrecomputedVerifyingKey {
+    /// Verification key
+    verifying_key: VerifyingKey,
+
+    /// Window table for the public point
+    point: PrecomputedPoint,
+
+    /// Window table for the generator
+    generator: PrecomputedPoint,
+}
+
+impl PrecomputedVerifyingKey {
+    /// Borrow the [`VerifyingKey`] this table was computed for.
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Verify the prehashed message `z` against the provided signature.
+    fn verify_prehashed(&self, z: FieldBytes, signature: &Signature) -> Result<(), Error> {
+        let (r, s) = signature.split_scalars();
+        let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(z);
+
+        let s_inv = *s.invert();
+        let u1 = z * s_inv;
+        let u2 = *r * s_inv;
+
+        // Verification only involves public values, so variable time is fine
+        let x = PrecomputedPoint::lincomb_vartime(&self.generator, &u1, &self.point, &u2)
+            .to_affine()
+            .x();
+
+        if <Scalar as Reduce<U256>>::from_be_bytes_reduced(x).eq(&r) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+#[cfg(feature = "sha256")]
+impl<S> signature::Verifier<S> for PrecomputedVerifyingKey
+where
+    S: PrehashSignature,
+    Self: DigestVerifier<S::Digest, S>,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        self.verify_digest(S::Digest::new_with_prefix(msg), signature)
+    }
+}
+
+/// Digests of any size are accepted, as with [`VerifyingKey`].
+impl<D> DigestVerifier<D, Signature> for PrecomputedVerifyingKey
+where
+    D: Digest + FixedOutput,
+{
+    fn verify_digest(&self, digest: D, signature: &Signature) -> Result<(), Error> {
+        self.verify_prehashed(bits2field(&digest.finalize_fixed()), signature)
+    }
+}
+
+impl<D> DigestVerifier<D, recoverable::Signature> for PrecomputedVerifyingKey
+where
+    D: Digest + FixedOutput,
+{
+    fn verify_digest(&self, digest: D, signature: &recoverable::Signature) -> Result<(), Error> {
+        self.verify_digest(digest, &Signature::from(*signature))
+    }
+}
+
+/// [`VerifyingKey`] along with the SHA-256 state after hashing a common
+/// message prefix.
+///
+/// Verifying `suffix` is equivalent to verifying `prefix || suffix` with the
+/// [`VerifyingKey`], without rehashing the prefix every time. Create one with
+/// [`VerifyingKey::verifier_with_prefix`].
+#[cfg(feature = "sha256")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "ecdsa", feature = "sha256"))))]
+#[derive(Clone, Debug)]
+pub struct PrefixVerifier {
+    /// Verification key
+    verifying_key: VerifyingKey,
+
+    /// SHA-256 state after hashing the prefix
+    digest: Sha256,
+}
+
+#[cfg(feature = "sha256")]
+impl PrefixVerifier {
+    /// Borrow the [`VerifyingKey`] signatures are verified with.
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Verify a signature over the message `prefix || suffix`.
+    pub fn verify_suffix<S>(&self, suffix: &[u8], signature: &S) -> Result<(), Error>
+    where
+        S: signature::Signature,
+        VerifyingKey: DigestVerifier<Sha256, S>,
+    {
+        let digest = self.digest.clone().chain_update(suffix);
+        self.verifying_key.verify_digest(digest, signature)
+    }
+}
+
+impl From<VerifyingKey> for PrecomputedVerifyingKey {
+    fn from(verifying_key: VerifyingKey) -> PrecomputedVerifyingKey {
+        PrecomputedVerifyingKey {
+            verifying_key,
+            point: PrecomputedPoint::new(&ProjectivePoint::from(verifying_key)),
+            generator: PrecomputedPoint::new(&ProjectivePoint::GENERATOR),
+        }
+    }
+}
+
+impl From<&VerifyingKey> for PrecomputedVerifyingKey {
+    fn from(verifying_key: &VerifyingKey) -> PrecomputedVerifyingKey {
+        PrecomputedVerifyingKey::from(*verifying_key)
+    }
+}
+
+/// Verify several signatures which were all produced over the same
+/// prehashed message `z`, e.g. the attestations of a multisig quorum.
+///
+/// Each signature is still checked on its own, but the inversions are
+/// shared: every `s⁻¹` is computed with a single [`Scalar::batch_invert`],
+/// and every `R = u1 * G + u2 * Q` is converted to affine coordinates with a
+/// single [`ProjectivePoint::to_affine_batch`]. Since `z` is shared,
+/// `u1 = z * s⁻¹` then costs a single multiplication per signature.
+///
+/// Returns `Ok(())` only if every signature is valid. An empty slice is
+/// rejected.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "alloc", feature = "ecdsa"))))]
+pub fn verify_multisig(
+    msg_digest: &Scalar,
+    items: &[(VerifyingKey, Signature)],
+) -> Result<(), Error> {
+    if items.is_empty() {
+        return Err(Error::new());
+    }
+
+    // Every `s` is nonzero, so this can't fail
+    let mut s_invs = items
+        .iter()
+        .map(|(_, signature)| *signature.s())
+        .collect::<Vec<Scalar>>();
+    let _ = Scalar::batch_invert(&mut s_invs);
+
+    let points = items
+        .iter()
+        .zip(&s_invs)
+        .map(|((verifying_key, signature), s_inv)| {
+            ProjectivePoint::lincomb(
+                &ProjectivePoint::GENERATOR,
+                &(msg_digest * s_inv),
+                &ProjectivePoint::from(verifying_key),
+                &(*signature.r() * s_inv),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // The identity has an `x`-coordinate of zero, which never equals `r`
+    let valid = items
+        .iter()
+        .zip(ProjectivePoint::to_affine_batch(&points))
+        .all(|((_, signature), point)| {
+            <Scalar as Reduce<U256>>::from_be_bytes_reduced(point.x()) == *signature.r()
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Verify the given message against a signature using SHA-256 under each of
+/// the given candidate keys, e.g. when the signer may have used any of
+/// several rotated keys.
+///
+/// The message is only hashed once. Returns the index of the first key
+/// under which the signature verifies, or an error if there is none.
+#[cfg(feature = "sha256")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sha256")))]
+pub fn verify_any(
+    keys: &[VerifyingKey],
+    msg: &[u8],
+    signature: &Signature,
+) -> Result<usize, Error> {
+    let z = Sha256::digest(msg);
+
+    keys.iter()
+        .position(|key| {
+            PublicKey::from(key)
+                .as_affine()
+                .verify_prehashed(z, signature)
+                .is_ok()
+        })
+        .ok_or_else(Error::new)
+}
+
+/// Verify a signature over the message read from `reader`, hashing it with
+/// SHA-256 as it is streamed, so large inputs (e.g. files) never need to be
+/// held in memory at once.
+///
+/// The outer [`io::Result`][`std::io::Result`] reports failures reading the
+/// message; the inner [`Result`] is the outcome of verification.
+#[cfg(all(feature = "std", feature = "sha256"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", feature = "sha256"))))]
+pub fn verify_reader<R: std::io::Read>(
+    verifying_key: &VerifyingKey,
+    signature: &Signature,
+    mut reader: R,
+) -> std::io::Result<Result<(), Error>> {
+    let mut digest = Sha256::new();
+    let mut buf = [0u8; 8192];
+
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => digest.update(&buf[..n]),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(verifying_key.verify_digest(digest, signature))
+}
+
+/// Verify a fixed-size signature over a 32-byte message hash under a
+/// SEC1-encoded public key, all given as raw byte slices, e.g. as received
+/// over an FFI boundary.
+///
+/// Each way in which the inputs can be rejected maps to its own error:
+///
+/// - public key: any error from [`VerifyingKey::from_sec1_bytes_strict`]
+/// - signature: [`P256Error::InvalidSignatureLength`] unless it is exactly
+///   64 bytes, [`P256Error::InvalidR`] or [`P256Error::InvalidS`]
+/// - otherwise [`P256Error::VerificationFailed`]
+#[cfg_attr(docsrs, doc(cfg(feature = "ecdsa")))]
+pub fn verify_prehash(
+    pubkey_sec1: &[u8],
+    msg_hash: &[u8; 32],
+    sig: &[u8],
+) -> Result<(), P256Error> {
+    let verifying_key = VerifyingKey::from_sec1_bytes_strict(pubkey_sec1)?;
+
+    if sig.len() != 64 {
+        return Err(P256Error::InvalidSignatureLength);
+    }
+
+    let (r, s) = check_scalars(
+        &FieldBytes::clone_from_slice(&sig[..32]),
+        &FieldBytes::clone_from_slice(&sig[32..]),
+    )?;
+    let signature = Signature::from_scalars(r, s).map_err(|_| P256Error::InvalidEncoding)?;
+
+    PublicKey::from(verifying_key)
+        .as_affine()
+        .verify_prehashed((*msg_hash).into(), &signature)
+        .map_err(|_| P256Error::VerificationFailed)
+}
+
+/// Decode the big endian `r` and `s` components of a signature, rejecting
+/// either one with [`P256Error::InvalidR`] or [`P256Error::InvalidS`] if it
+/// is zero or not less than the group order.
+fn check_scalars(
+    r: &FieldBytes,
+    s: &FieldBytes,
+) -> Result<(NonZeroScalar, NonZeroScalar), P256Error> {
+    let r = Option::from(NonZeroScalar::from_repr(*r)).ok_or(P256Error::InvalidR)?;
+    let s = Option::from(NonZeroScalar::from_repr(*s)).ok_or(P256Error::InvalidS)?;
+    Ok((r, s))
+}
+
+impl ConstantTimeEq for VerifyingKey {
+    /// Compare the canonical (compressed) SEC1 encodings of both keys in
+    /// constant time.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_bytes()
+            .as_slice()
+            .ct_eq(other.to_bytes().as_slice())
+    }
+}
+
+impl ConditionallySelectable for VerifyingKey {
+    /// Select between the affine points of both keys in constant time.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let point = AffinePoint::conditional_select(
+            PublicKey::from(a).as_affine(),
+            PublicKey::from(b).as_affine(),
+            choice,
+        );
+
+        VerifyingKey::try_from(point).expect("both keys are valid")
+    }
+}
+
+impl Neg for VerifyingKey {
+    type Output = VerifyingKey;
+
+    fn neg(self) -> VerifyingKey {
+        self.negate()
+    }
+}
+
+impl Neg for &VerifyingKey {
+    type Output = VerifyingKey;
+
+    fn neg(self) -> VerifyingKey {
+        self.negate()
+    }
+}
+
 impl From<PublicKey> for VerifyingKey {
     fn from(public_key: PublicKey) -> VerifyingKey {
         Self {
@@ -236,10 +990,163 @@ impl TryFrom<pkcs8::SubjectPublicKeyInfo<'_>> for VerifyingKey {
     }
 }
 
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl VerifyingKey {
+    /// Parse a [`VerifyingKey`] from a `SubjectPublicKeyInfo`, or return
+    /// `Ok(None)` if it holds a key for another curve or algorithm.
+    ///
+    /// Unlike the [`TryFrom`] impl, this lets multi-curve code tell "not a
+    /// P-256 key" apart from "malformed P-256 key", which is still an error,
+    /// as is an `id-ecPublicKey` SPKI without a named curve.
+    pub fn from_spki_if_p256(
+        spki: pkcs8::SubjectPublicKeyInfo<'_>,
+    ) -> pkcs8::spki::Result<Option<Self>> {
+        if spki.algorithm.oid != elliptic_curve::ALGORITHM_OID
+            || !NistP256::matches_oid(&spki.algorithm.parameters_oid()?)
+        {
+            return Ok(None);
+        }
+
+        Self::try_from(spki).map(Some)
+    }
+}
+
 #[cfg(feature = "pkcs8")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
 impl DecodePublicKey for VerifyingKey {}
 
+#[cfg(feature = "x509")]
+#[cfg_attr(docsrs, doc(cfg(feature = "x509")))]
+impl VerifyingKey {
+    /// Extract the [`VerifyingKey`] from the `subjectPublicKeyInfo` of a
+    /// DER-encoded X.509 certificate.
+    ///
+    /// Only the structure of the certificate is parsed: its signature,
+    /// validity and extensions are **not** checked, which is the job of
+    /// whatever validates the chain. Returns an error unless the key's
+    /// algorithm is `id-ecPublicKey` with the `prime256v1` named curve.
+    pub fn from_x509_der(bytes: &[u8]) -> Result<Self, Error> {
+        use pkcs8::der::{asn1::AnyRef, Decode, Reader, SliceReader, Tag, TagNumber};
+
+        let mut reader = SliceReader::new(bytes).map_err(|_| Error::new())?;
+
+        let spki = reader
+            .sequence(|certificate| {
+                let spki = certificate.sequence(|tbs_certificate| {
+                    // version [0] EXPLICIT Version DEFAULT v1
+                    let version = Tag::ContextSpecific {
+                        constructed: true,
+                        number: TagNumber::N0,
+                    };
+
+                    if tbs_certificate.peek_tag()? == version {
+                        AnyRef::decode(tbs_certificate)?;
+                    }
+
+                    // serialNumber, signature, issuer, validity, subject
+                    for _ in 0..5 {
+                        AnyRef::decode(tbs_certificate)?;
+                    }
+
+                    let spki = pkcs8::SubjectPublicKeyInfo::decode(tbs_certificate)?;
+
+                    // issuerUniqueID, subjectUniqueID, extensions
+                    while !tbs_certificate.is_finished() {
+                        AnyRef::decode(tbs_certificate)?;
+                    }
+
+                    Ok(spki)
+                })?;
+
+                // signatureAlgorithm, signatureValue
+                AnyRef::decode(certificate)?;
+                AnyRef::decode(certificate)?;
+                Ok(spki)
+            })
+            .and_then(|spki| reader.finish(spki))
+            .map_err(|_| Error::new())?;
+
+        spki.algorithm
+            .assert_oids(
+                elliptic_curve::ALGORITHM_OID,
+                <NistP256 as pkcs8::AssociatedOid>::OID,
+            )
+            .map_err(|_| Error::new())?;
+
+        Self::try_from(spki).map_err(|_| Error::new())
+    }
+}
+
+/// Owned `SubjectPublicKeyInfo` for a [`VerifyingKey`].
+///
+/// [`pkcs8::SubjectPublicKeyInfo`] borrows its `subjectPublicKey` bytes, so it
+/// can't be returned from a key without something to hold them. This type
+/// owns the uncompressed SEC1 encoding of the key and hands out the
+/// structured form with [`SubjectPublicKeyInfoOwned::as_spki`]. It also
+/// implements [`der::Encode`][`pkcs8::der::Encode`], so it can be embedded
+/// directly in other DER structures.
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubjectPublicKeyInfoOwned {
+    subject_public_key: EncodedPoint,
+}
+
+#[cfg(feature = "pkcs8")]
+impl SubjectPublicKeyInfoOwned {
+    /// `id-ecPublicKey` algorithm identifier with the `secp256r1` named curve
+    /// as its parameters.
+    pub fn algorithm(&self) -> pkcs8::AlgorithmIdentifier<'static> {
+        pkcs8::AlgorithmIdentifier {
+            oid: elliptic_curve::ALGORITHM_OID,
+            parameters: Some((&<NistP256 as pkcs8::AssociatedOid>::OID).into()),
+        }
+    }
+
+    /// Uncompressed SEC1 encoding of the public key.
+    pub fn subject_public_key(&self) -> &[u8] {
+        self.subject_public_key.as_bytes()
+    }
+
+    /// Borrow this as a [`pkcs8::SubjectPublicKeyInfo`].
+    pub fn as_spki(&self) -> pkcs8::SubjectPublicKeyInfo<'_> {
+        pkcs8::SubjectPublicKeyInfo {
+            algorithm: self.algorithm(),
+            subject_public_key: self.subject_public_key(),
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl pkcs8::der::Encode for SubjectPublicKeyInfoOwned {
+    fn encoded_len(&self) -> pkcs8::der::Result<pkcs8::der::Length> {
+        self.as_spki().encoded_len()
+    }
+
+    fn encode(&self, writer: &mut dyn pkcs8::der::Writer) -> pkcs8::der::Result<()> {
+        self.as_spki().encode(writer)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl From<&VerifyingKey> for SubjectPublicKeyInfoOwned {
+    fn from(verifying_key: &VerifyingKey) -> Self {
+        Self {
+            subject_public_key: verifying_key.to_encoded_point(false),
+        }
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl TryFrom<&SubjectPublicKeyInfoOwned> for VerifyingKey {
+    type Error = pkcs8::spki::Error;
+
+    fn try_from(spki: &SubjectPublicKeyInfoOwned) -> pkcs8::spki::Result<Self> {
+        Self::try_from(spki.as_spki())
+    }
+}
+
 #[cfg(feature = "pem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
 impl FromStr for VerifyingKey {
@@ -250,25 +1157,65 @@ impl FromStr for VerifyingKey {
     }
 }
 
-#[cfg(all(feature = "pem", feature = "serde"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "serde"))))]
+/// DER encoding of a P-256 `SubjectPublicKeyInfo` (`id-ecPublicKey` with the
+/// `secp256r1` named curve) up to the start of an uncompressed SEC1 point.
+#[cfg(feature = "serde")]
+const SPKI_UNCOMPRESSED_PREFIX: [u8; 26] = [
+    0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a,
+    0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+];
+
+/// Same as [`SPKI_UNCOMPRESSED_PREFIX`], for a compressed SEC1 point.
+#[cfg(feature = "serde")]
+const SPKI_COMPRESSED_PREFIX: [u8; 26] = [
+    0x30, 0x39, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08, 0x2a,
+    0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x22, 0x00,
+];
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl Serialize for VerifyingKey {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: ser::Serializer,
     {
-        self.inner.serialize(serializer)
+        if serializer.is_human_readable() {
+            let mut der = [0u8; SPKI_UNCOMPRESSED_PREFIX.len() + 65];
+            let (prefix, point) = der.split_at_mut(SPKI_UNCOMPRESSED_PREFIX.len());
+            prefix.copy_from_slice(&SPKI_UNCOMPRESSED_PREFIX);
+            point.copy_from_slice(PublicKey::from(self).to_encoded_point(false).as_bytes());
+            return serdect::slice::serialize_hex_upper_or_bin(&der, serializer);
+        }
+
+        serdect::array::serialize_hex_upper_or_bin(&self.to_bytes(), serializer)
     }
 }
 
-#[cfg(all(feature = "pem", feature = "serde"))]
-#[cfg_attr(docsrs, doc(cfg(all(feature = "pem", feature = "serde"))))]
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 impl<'de> Deserialize<'de> for VerifyingKey {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        ecdsa_core::VerifyingKey::<NistP256>::deserialize(deserializer).map(Into::into)
+        if deserializer.is_human_readable() {
+            let mut buffer = [0u8; SPKI_UNCOMPRESSED_PREFIX.len() + 65];
+            let der = serdect::slice::deserialize_hex_or_bin(&mut buffer, deserializer)?;
+            let point = der
+                .strip_prefix(&SPKI_UNCOMPRESSED_PREFIX[..])
+                .filter(|point| point.len() == 65)
+                .or_else(|| {
+                    der.strip_prefix(&SPKI_COMPRESSED_PREFIX[..])
+                        .filter(|point| point.len() == 33)
+                })
+                .ok_or_else(|| de::Error::custom("invalid P-256 SubjectPublicKeyInfo"))?;
+
+            return Self::from_sec1_bytes(point).map_err(de::Error::custom);
+        }
+
+        let mut bytes = [0u8; 33];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Self::from_sec1_bytes(&bytes).map_err(de::Error::custom)
     }
 }
 
@@ -293,4 +1240,1094 @@ mod tests {
         assert!(sig.normalize_s().is_none()); // Ensure signature is already normalized
         assert!(verifying_key.verify(&msg, &sig).is_ok());
     }
+
+    #[test]
+    fn from_sec1_bytes_strict() {
+        use crate::ecdsa::P256Error;
+
+        let mut bytes = hex!("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+        assert!(VerifyingKey::from_sec1_bytes_strict(&bytes).is_ok());
+        assert_eq!(
+            VerifyingKey::from_sec1_bytes_strict(&bytes[..32]),
+            Err(P256Error::InvalidEncoding)
+        );
+        assert_eq!(
+            VerifyingKey::from_sec1_bytes_strict(&[0]),
+            Err(P256Error::Identity)
+        );
+
+        // x = p - 1 is not the x-coordinate of any point on the curve
+        bytes[1..].copy_from_slice(&hex!(
+            "ffffffff00000001000000000000000000000000fffffffffffffffffffffffe"
+        ));
+        assert_eq!(
+            VerifyingKey::from_sec1_bytes_strict(&bytes),
+            Err(P256Error::PointNotOnCurve)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn from_sec1_bytes_malformed() {
+        use crate::{ecdsa::P256Error, EncodedPoint};
+
+        /// p, p - 1 and 2^256 - 1
+        const P: [u8; 32] =
+            hex!("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+        const P_MINUS_1: [u8; 32] =
+            hex!("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe");
+        const ALL_FF: [u8; 32] = [0xff; 32];
+
+        let generator = hex!(
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2"
+            "964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        );
+        let (gx, gy) = (&generator[1..33], &generator[33..]);
+
+        let compressed = |tag: u8, x: &[u8]| [&[tag][..], x].concat();
+        let uncompressed = |x: &[u8], y: &[u8]| [&[0x04][..], x, y].concat();
+
+        let cases: &[(&[u8], P256Error)] = &[
+            // All zeros beyond the tag
+            (
+                &uncompressed(&[0; 32], &[0; 32]),
+                P256Error::PointNotOnCurve,
+            ),
+            // All ones beyond the tag
+            (&compressed(0x02, &ALL_FF), P256Error::CoordinateOutOfRange),
+            (
+                &uncompressed(&ALL_FF, &ALL_FF),
+                P256Error::CoordinateOutOfRange,
+            ),
+            // x = p, which would alias x = 0
+            (&compressed(0x02, &P), P256Error::CoordinateOutOfRange),
+            (&compressed(0x03, &P), P256Error::CoordinateOutOfRange),
+            (&uncompressed(&P, gy), P256Error::CoordinateOutOfRange),
+            // y = p
+            (&uncompressed(gx, &P), P256Error::CoordinateOutOfRange),
+            // x with no corresponding y
+            (&compressed(0x03, &P_MINUS_1), P256Error::PointNotOnCurve),
+            (&uncompressed(&P_MINUS_1, gy), P256Error::PointNotOnCurve),
+            // Valid x with the wrong y
+            (&uncompressed(gx, &[0; 32]), P256Error::PointNotOnCurve),
+            (&uncompressed(gx, &P_MINUS_1), P256Error::PointNotOnCurve),
+            // Bad tags and lengths
+            (&compressed(0x04, gx), P256Error::InvalidEncoding),
+            (&compressed(0x01, gx), P256Error::InvalidEncoding),
+            (&[0x02], P256Error::InvalidEncoding),
+            (&[], P256Error::InvalidEncoding),
+            (&[0; 33], P256Error::InvalidEncoding),
+        ];
+
+        assert!(VerifyingKey::from_sec1_bytes_strict(&generator).is_ok());
+
+        // `b` is a square, so x = 0 (unlike x = p) is a valid public key
+        for tag in [0x02, 0x03] {
+            let x_zero = VerifyingKey::from_sec1_bytes_strict(&compressed(tag, &[0; 32])).unwrap();
+            assert_eq!(x_zero.coordinates().0, [0; 32]);
+        }
+
+        for (bytes, err) in cases {
+            assert_eq!(VerifyingKey::from_sec1_bytes_strict(bytes), Err(*err));
+            assert!(VerifyingKey::from_sec1_bytes(bytes).is_err());
+
+            if let Ok(encoded_point) = EncodedPoint::from_bytes(bytes) {
+                assert!(VerifyingKey::from_encoded_point(&encoded_point).is_err());
+            }
+        }
+    }
+
+    /// Wycheproof tcId: 304
+    #[test]
+    fn verify_strict() {
+        use crate::ecdsa::P256Error;
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(&hex!(
+            "02c156afee1ce52ef83a0dd168c1144eb20008697e6664fa132ba23c128cce8055"
+        ))
+        .unwrap();
+
+        let msg = hex!("313233343030");
+        let sig = Signature::from_der(&hex!("30440220784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792022016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b")).unwrap();
+        assert_eq!(verifying_key.verify_strict(&msg, &sig), Ok(()));
+        assert_eq!(
+            verifying_key.verify_strict(b"other", &sig),
+            Err(P256Error::VerificationFailed)
+        );
+
+        let (r, s) = sig.split_scalars();
+        let high_s = Signature::from_scalars(*r, -*s).unwrap();
+        assert_eq!(
+            verifying_key.verify_strict(&msg, &high_s),
+            Err(P256Error::Malleable)
+        );
+    }
+
+    mod policy {
+        use crate::ecdsa::{P256Error, Signature, VerificationPolicy, VerifyingKey};
+        use hex_literal::hex;
+
+        const MSG: &[u8] = &hex!("313233343030");
+
+        /// Wycheproof tcId: 304
+        const SIGNATURE: &[u8] = &hex!("30440220784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792022016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b");
+
+        /// Same signature as above with an unnecessary leading zero on `r`
+        const PADDED_SIGNATURE: &[u8] = &hex!("3045022100784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792022016c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b");
+
+        fn verifying_key() -> VerifyingKey {
+            VerifyingKey::from_sec1_bytes(&hex!(
+                "02c156afee1ce52ef83a0dd168c1144eb20008697e6664fa132ba23c128cce8055"
+            ))
+            .unwrap()
+        }
+
+        fn high_s_signature() -> Signature {
+            let (r, s) = Signature::from_der(SIGNATURE).unwrap().split_scalars();
+            Signature::from_scalars(*r, -*s).unwrap()
+        }
+
+        #[test]
+        fn default() {
+            let key = verifying_key();
+            let policy = VerificationPolicy::default();
+            let high_s = high_s_signature().to_der();
+
+            assert_eq!(key.verify_with_policy(MSG, SIGNATURE, policy), Ok(()));
+            assert_eq!(
+                key.verify_with_policy(MSG, high_s.as_bytes(), policy),
+                Ok(())
+            );
+            assert_eq!(
+                key.verify_with_policy(MSG, PADDED_SIGNATURE, policy),
+                Err(P256Error::InvalidEncoding)
+            );
+            assert_eq!(
+                key.verify_with_policy(b"other", SIGNATURE, policy),
+                Err(P256Error::VerificationFailed)
+            );
+        }
+
+        #[test]
+        fn reject_high_s() {
+            let key = verifying_key();
+            let policy = VerificationPolicy {
+                reject_high_s: true,
+                ..VerificationPolicy::LENIENT
+            };
+            let high_s = high_s_signature().to_der();
+
+            assert_eq!(key.verify_with_policy(MSG, SIGNATURE, policy), Ok(()));
+            assert_eq!(
+                key.verify_with_policy(MSG, PADDED_SIGNATURE, policy),
+                Ok(())
+            );
+            assert_eq!(
+                key.verify_with_policy(MSG, high_s.as_bytes(), policy),
+                Err(P256Error::Malleable)
+            );
+        }
+
+        #[test]
+        fn reject_non_minimal_der() {
+            let key = verifying_key();
+            let policy = VerificationPolicy {
+                reject_non_minimal_der: true,
+                ..VerificationPolicy::LENIENT
+            };
+            let high_s = high_s_signature().to_der();
+
+            assert_eq!(key.verify_with_policy(MSG, SIGNATURE, policy), Ok(()));
+            assert_eq!(
+                key.verify_with_policy(MSG, high_s.as_bytes(), policy),
+                Ok(())
+            );
+            assert_eq!(
+                key.verify_with_policy(MSG, PADDED_SIGNATURE, policy),
+                Err(P256Error::InvalidEncoding)
+            );
+        }
+
+        #[test]
+        fn reject_zero_digest() {
+            use crate::{ecdsa::SigningKey, NistP256};
+            use elliptic_curve::{bigint::Encoding, Curve};
+
+            let signing_key = SigningKey::from_bytes(&[0x42; 32]).unwrap();
+            let key = signing_key.verifying_key();
+            let signature = Signature::from(
+                signing_key
+                    .sign_prehash_recoverable(Default::default())
+                    .unwrap(),
+            )
+            .to_der();
+            let policy = VerificationPolicy {
+                reject_zero_digest: true,
+                ..VerificationPolicy::LENIENT
+            };
+
+            // Both reduce to zero modulo `n`
+            let zero = [0u8; 32];
+            let order = NistP256::ORDER.to_be_bytes();
+
+            for prehash in [zero, order] {
+                assert_eq!(
+                    key.verify_prehash_with_policy(
+                        &prehash,
+                        signature.as_bytes(),
+                        VerificationPolicy::LENIENT
+                    ),
+                    Ok(())
+                );
+                assert_eq!(
+                    key.verify_prehash_with_policy(&prehash, signature.as_bytes(), policy),
+                    Err(P256Error::ZeroDigest)
+                );
+                assert_eq!(
+                    key.verify_prehash_with_policy(
+                        &prehash,
+                        signature.as_bytes(),
+                        VerificationPolicy::STRICT
+                    ),
+                    Err(P256Error::ZeroDigest)
+                );
+            }
+        }
+
+        #[test]
+        fn strict() {
+            let key = verifying_key();
+            let policy = VerificationPolicy::STRICT;
+            let high_s = high_s_signature().to_der();
+
+            assert_eq!(key.verify_with_policy(MSG, SIGNATURE, policy), Ok(()));
+            assert!(key
+                .verify_with_policy(MSG, PADDED_SIGNATURE, policy)
+                .is_err());
+            assert!(key
+                .verify_with_policy(MSG, high_s.as_bytes(), policy)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn fingerprint_is_encoding_independent() {
+        let compressed = VerifyingKey::from_sec1_bytes(&hex!(
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ))
+        .unwrap();
+        let uncompressed = VerifyingKey::from_sec1_bytes(&hex!(
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296
+             4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            compressed.fingerprint_sha256(),
+            uncompressed.fingerprint_sha256()
+        );
+    }
+
+    #[test]
+    fn verify_digest_rs() {
+        use crate::ecdsa::P256Error;
+        use sha2::{Digest, Sha256};
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(&hex!(
+            "02c156afee1ce52ef83a0dd168c1144eb20008697e6664fa132ba23c128cce8055"
+        ))
+        .unwrap();
+        let digest = Sha256::new_with_prefix(hex!("313233343030"));
+        let r = hex!("784eea04d4a9e68260ba55b39277b2221db3793e47ec5c9301e43b45c7285792");
+        let s = hex!("16c4c411c20aa62c314ad383d00aa1e6c145641d7ce10e52075fb10e7d8bec2b");
+        assert!(verifying_key
+            .verify_digest_rs(digest.clone(), &r, &s)
+            .is_ok());
+
+        let n = hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        for invalid in [[0; 32], n, [0xff; 32]] {
+            assert_eq!(
+                verifying_key.verify_digest_rs(digest.clone(), &invalid, &s),
+                Err(P256Error::InvalidR)
+            );
+            assert_eq!(
+                verifying_key.verify_digest_rs(digest.clone(), &r, &invalid),
+                Err(P256Error::InvalidS)
+            );
+        }
+
+        assert_eq!(
+            verifying_key.verify_digest_rs(digest, &s, &r),
+            Err(P256Error::VerificationFailed)
+        );
+    }
+
+    #[test]
+    fn coordinates() {
+        let key = VerifyingKey::from_sec1_bytes(&hex!(
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ))
+        .unwrap();
+
+        let (x, y) = key.coordinates();
+        assert_eq!(
+            x,
+            hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296")
+        );
+        assert_eq!(
+            y,
+            hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5")
+        );
+    }
+
+    #[test]
+    fn to_compressed_unchecked() {
+        use elliptic_curve::rand_core::OsRng;
+
+        let keys = [
+            VerifyingKey::from_sec1_bytes(&hex!(
+                "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+            ))
+            .unwrap(),
+            VerifyingKey::from_sec1_bytes(&hex!(
+                "02c156afee1ce52ef83a0dd168c1144eb20008697e6664fa132ba23c128cce8055"
+            ))
+            .unwrap(),
+            crate::ecdsa::SigningKey::random(&mut OsRng).verifying_key(),
+        ];
+
+        for key in keys {
+            assert_eq!(key.to_compressed_unchecked()[..], key.to_bytes()[..]);
+            assert_eq!(
+                key.negate().to_compressed_unchecked()[..],
+                key.negate().to_bytes()[..]
+            );
+        }
+    }
+
+    #[test]
+    fn encodes_same_point() {
+        use elliptic_curve::{rand_core::OsRng, sec1::ToEncodedPoint};
+
+        let key = crate::ecdsa::SigningKey::random(&mut OsRng).verifying_key();
+        let compressed = key.to_encoded_point(true);
+        let uncompressed = key.to_encoded_point(false);
+
+        assert!(key.encodes_same_point(compressed.as_bytes()));
+        assert!(key.encodes_same_point(uncompressed.as_bytes()));
+        assert!(VerifyingKey::from_sec1_bytes(uncompressed.as_bytes())
+            .unwrap()
+            .encodes_same_point(compressed.as_bytes()));
+
+        let other = crate::ecdsa::SigningKey::random(&mut OsRng).verifying_key();
+        assert!(!key.encodes_same_point(other.to_encoded_point(false).as_bytes()));
+        assert!(!key.encodes_same_point(key.negate().to_encoded_point(true).as_bytes()));
+        assert!(!key.encodes_same_point(&uncompressed.as_bytes()[..64]));
+        assert!(!key.encodes_same_point(&[0]));
+    }
+
+    #[test]
+    fn ct_eq() {
+        use elliptic_curve::subtle::ConstantTimeEq;
+
+        let compressed = VerifyingKey::from_sec1_bytes(&hex!(
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ))
+        .unwrap();
+        let uncompressed = VerifyingKey::from_sec1_bytes(&hex!(
+            "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296
+             4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+        ))
+        .unwrap();
+        let negated = VerifyingKey::from_sec1_bytes(&hex!(
+            "026b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ))
+        .unwrap();
+
+        for (a, b) in [
+            (compressed, uncompressed),
+            (compressed, negated),
+            (negated, negated),
+        ] {
+            assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            assert_eq!(bool::from(b.ct_eq(&a)), a == b);
+        }
+
+        assert!(bool::from(compressed.ct_eq(&uncompressed)));
+        assert!(!bool::from(compressed.ct_eq(&negated)));
+    }
+
+    #[test]
+    fn conditional_select() {
+        use elliptic_curve::subtle::{Choice, ConditionallySelectable};
+
+        let a = VerifyingKey::from_sec1_bytes(&hex!(
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        ))
+        .unwrap();
+        let b = VerifyingKey::from_sec1_bytes(&hex!(
+            "02c156afee1ce52ef83a0dd168c1144eb20008697e6664fa132ba23c128cce8055"
+        ))
+        .unwrap();
+
+        assert_eq!(VerifyingKey::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(VerifyingKey::conditional_select(&a, &b, Choice::from(1)), b);
+
+        let mut c = a;
+        c.conditional_assign(&b, Choice::from(0));
+        assert_eq!(c, a);
+        c.conditional_assign(&b, Choice::from(1));
+        assert_eq!(c, b);
+    }
+
+    mod constant_time {
+        use crate::{
+            ecdsa::{Signature, VerifyingKey},
+            test_vectors::ecdsa::ECDSA_TEST_VECTORS,
+            EncodedPoint, FieldBytes, PublicKey,
+        };
+        use ecdsa_core::hazmat::VerifyPrimitive;
+        use elliptic_curve::generic_array::GenericArray;
+
+        #[test]
+        fn matches_variable_time() {
+            for vector in ECDSA_TEST_VECTORS {
+                let key = VerifyingKey::from_encoded_point(&EncodedPoint::from_affine_coordinates(
+                    GenericArray::from_slice(vector.q_x),
+                    GenericArray::from_slice(vector.q_y),
+                    false,
+                ))
+                .unwrap();
+                let signature = Signature::from_scalars(
+                    *GenericArray::from_slice(vector.r),
+                    *GenericArray::from_slice(vector.s),
+                )
+                .unwrap();
+                let affine = *PublicKey::from(key).as_affine();
+
+                let z = FieldBytes::clone_from_slice(vector.m);
+                let mut other_z = z;
+                other_z[0] ^= 1;
+
+                for z in [z, other_z] {
+                    assert_eq!(
+                        bool::from(key.verify_prehashed_ct(&z, &signature)),
+                        affine.verify_prehashed(z, &signature).is_ok()
+                    );
+                }
+
+                assert!(bool::from(key.verify_prehashed_ct(&z, &signature)));
+                assert!(!bool::from(key.verify_prehashed_ct(&other_z, &signature)));
+            }
+        }
+    }
+
+    mod computed_r {
+        use crate::{
+            ecdsa::{signature::Signer, Signature, SigningKey},
+            FieldBytes,
+        };
+        use elliptic_curve::rand_core::OsRng;
+        use sha2::{Digest, Sha256};
+
+        #[test]
+        fn matches_r() {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let verifying_key = signing_key.verifying_key();
+
+            for msg in [&b"first"[..], b"second", b"third"] {
+                let signature: Signature = signing_key.sign(msg);
+                let z = FieldBytes::from(Sha256::digest(msg));
+                let (computed_r, valid) = verifying_key
+                    .verify_returning_computed_r(&z, &signature)
+                    .unwrap();
+                assert_eq!(computed_r, *signature.r());
+                assert!(valid);
+
+                // Mismatches still return the computed value
+                let mut other_z = z;
+                other_z[0] ^= 1;
+                let (computed_r, valid) = verifying_key
+                    .verify_returning_computed_r(&other_z, &signature)
+                    .unwrap();
+                assert_ne!(computed_r, *signature.r());
+                assert!(!valid);
+
+                let (computed_r, valid) = SigningKey::random(&mut OsRng)
+                    .verifying_key()
+                    .verify_returning_computed_r(&z, &signature)
+                    .unwrap();
+                assert_ne!(computed_r, *signature.r());
+                assert!(!valid);
+            }
+        }
+    }
+
+    mod recovering {
+        use crate::ecdsa::{
+            recoverable,
+            signature::{DigestSigner, Signer},
+            Signature, SigningKey,
+        };
+        use elliptic_curve::rand_core::OsRng;
+        use sha2::{Digest, Sha256};
+
+        #[test]
+        fn attaches_recovery_id() {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let verifying_key = signing_key.verifying_key();
+
+            for i in 0u8..16 {
+                let msg = [i];
+                let digest = Sha256::new_with_prefix(msg);
+                let expected: recoverable::Signature = signing_key.sign_digest(digest.clone());
+                let signature = Signature::from(expected);
+
+                let recovered = verifying_key
+                    .verify_digest_recovering(digest.clone(), &signature)
+                    .unwrap();
+                assert_eq!(recovered, expected);
+                assert_eq!(
+                    recovered.recover_verifying_key(&msg).unwrap(),
+                    verifying_key
+                );
+
+                // High `s` flips the recovery ID rather than being normalized
+                let negated = Signature::from_scalars(signature.r(), -*signature.s()).unwrap();
+                let recovered = verifying_key
+                    .verify_digest_recovering(digest.clone(), &negated)
+                    .unwrap();
+                assert_eq!(Signature::from(recovered), negated);
+                assert_ne!(
+                    u8::from(recovered.recovery_id()),
+                    u8::from(expected.recovery_id())
+                );
+                assert_eq!(
+                    recovered.recover_verifying_key(&msg).unwrap(),
+                    verifying_key
+                );
+            }
+        }
+
+        #[test]
+        fn rejects_invalid() {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let signature: Signature = signing_key.sign(b"message");
+
+            assert!(signing_key
+                .verifying_key()
+                .verify_digest_recovering(Sha256::new_with_prefix(b"other"), &signature)
+                .is_err());
+            assert!(SigningKey::random(&mut OsRng)
+                .verifying_key()
+                .verify_digest_recovering(Sha256::new_with_prefix(b"message"), &signature)
+                .is_err());
+        }
+    }
+
+    mod boundary {
+        use crate::{
+            ecdsa::{
+                recoverable::{self, Id},
+                verify_prehash, P256Error, Signature, SigningKey, VerificationPolicy, VerifyingKey,
+            },
+            elliptic_curve::{
+                bigint::U256, ops::Reduce, rand_core::OsRng, sec1::ToEncodedPoint,
+                AffineXCoordinate,
+            },
+            FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar,
+        };
+        use ecdsa_core::hazmat::VerifyPrimitive;
+        use elliptic_curve::Field;
+
+        /// `1` and `n - 1`
+        fn extremes() -> [Scalar; 2] {
+            [Scalar::ONE, -Scalar::ONE]
+        }
+
+        fn assert_verifies(key: &VerifyingKey, z: &FieldBytes, signature: &Signature) {
+            let pubkey = key.to_encoded_point(false);
+            let prehash: [u8; 32] = (*z).into();
+            let mut other_z = *z;
+            other_z[31] ^= 1;
+
+            let affine = *PublicKey::from(key).as_affine();
+            assert!(affine.verify_prehashed(*z, signature).is_ok());
+            assert!(affine.verify_prehashed(other_z, signature).is_err());
+            assert!(bool::from(key.verify_prehashed_ct(z, signature)));
+            assert!(!bool::from(key.verify_prehashed_ct(&other_z, signature)));
+            assert_eq!(
+                verify_prehash(pubkey.as_bytes(), &prehash, signature.as_ref()),
+                Ok(())
+            );
+
+            let der = signature.to_der();
+            assert_eq!(
+                key.verify_prehash_with_policy(
+                    &prehash,
+                    der.as_bytes(),
+                    VerificationPolicy::LENIENT
+                ),
+                Ok(())
+            );
+
+            let expected = if signature.normalize_s().is_some() {
+                Err(P256Error::Malleable)
+            } else {
+                Ok(())
+            };
+            assert_eq!(
+                key.verify_prehash_with_policy(
+                    &prehash,
+                    der.as_bytes(),
+                    VerificationPolicy::STRICT
+                ),
+                expected
+            );
+        }
+
+        /// Sign with a fixed `s` by solving for the message: `z = s k - r d`
+        #[test]
+        fn extreme_s() {
+            let d = NonZeroScalar::random(&mut OsRng);
+            let key = SigningKey::from(d).verifying_key();
+
+            for s in extremes() {
+                let k = Scalar::random(&mut OsRng);
+                let x = (ProjectivePoint::GENERATOR * k).to_affine().x();
+                let r = <Scalar as Reduce<U256>>::from_be_bytes_reduced(x);
+                let z = (s * k - r * *d).to_bytes();
+
+                let signature = Signature::from_scalars(r.to_bytes(), s.to_bytes()).unwrap();
+                assert_verifies(&key, &z, &signature);
+
+                // Recovery yields the signing key for one of the IDs
+                let recovered = (0..=1)
+                    .filter_map(|id| {
+                        recoverable::Signature::new(&signature, Id::new(id).unwrap())
+                            .unwrap()
+                            .recover_verifying_key_from_digest_bytes(&z)
+                            .ok()
+                    })
+                    .filter(|recovered| *recovered == key)
+                    .count();
+                assert_eq!(recovered, 1);
+            }
+        }
+
+        /// Neither `1`, `n + 1` nor `n - 1` (`2n - 1` exceeds `p`) is the
+        /// `x`-coordinate of a curve point, so signatures with an extreme `r`
+        /// can't be produced by anyone and must be rejected by every key
+        /// without tripping up recovery or verification.
+        #[test]
+        fn extreme_r() {
+            let key = SigningKey::random(&mut OsRng).verifying_key();
+            let affine = *PublicKey::from(&key).as_affine();
+            let pubkey = key.to_encoded_point(true);
+            let z = FieldBytes::from([0x42; 32]);
+
+            for r in extremes() {
+                for s in extremes().into_iter().chain([Scalar::random(&mut OsRng)]) {
+                    let signature = Signature::from_scalars(r.to_bytes(), s.to_bytes()).unwrap();
+
+                    for id in 0..=1 {
+                        let id = Id::new(id).unwrap();
+                        let sig = recoverable::Signature::new(&signature, id).unwrap();
+                        assert!(bool::from(sig.r_point(id).is_none()));
+                        assert!(sig.recover_verifying_key_from_digest_bytes(&z).is_err());
+                    }
+
+                    assert!(affine.verify_prehashed(z, &signature).is_err());
+                    assert!(!bool::from(key.verify_prehashed_ct(&z, &signature)));
+                    assert_eq!(
+                        verify_prehash(pubkey.as_bytes(), &z.into(), signature.as_ref()),
+                        Err(P256Error::VerificationFailed)
+                    );
+                }
+            }
+        }
+    }
+
+    mod negate {
+        use crate::{
+            ecdsa::{signature::Signer, Signature, SigningKey},
+            ProjectivePoint, Scalar,
+        };
+        use elliptic_curve::{
+            bigint::U256,
+            ops::{Invert, Reduce},
+            rand_core::OsRng,
+            AffineXCoordinate,
+        };
+        use sha2::{Digest, Sha256};
+
+        #[test]
+        fn is_negative_of() {
+            let verifying_key = SigningKey::random(&mut OsRng).verifying_key();
+            let other = SigningKey::random(&mut OsRng).verifying_key();
+
+            assert!(bool::from(verifying_key.is_negative_of(&-verifying_key)));
+            assert!(bool::from((-verifying_key).is_negative_of(&verifying_key)));
+            assert!(!bool::from(verifying_key.is_negative_of(&verifying_key)));
+            assert!(!bool::from(verifying_key.is_negative_of(&other)));
+            assert!(!bool::from(verifying_key.is_negative_of(&-other)));
+        }
+
+        #[test]
+        fn negated_key() {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let verifying_key = signing_key.verifying_key();
+            let negated = -&verifying_key;
+
+            assert_ne!(negated, verifying_key);
+            assert_eq!(-negated, verifying_key);
+            assert_eq!(negated, verifying_key.negate());
+            assert_eq!(
+                ProjectivePoint::from(negated),
+                -ProjectivePoint::from(verifying_key)
+            );
+            assert_eq!(negated.to_bytes()[1..], verifying_key.to_bytes()[1..]);
+
+            // `u1 G + u2 Q = u1 G + (-u2) (-Q)`
+            let msg = b"negated key";
+            let signature: Signature = signing_key.sign(msg);
+            let (r, s) = signature.split_scalars();
+            let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(Sha256::digest(msg));
+            let s_inv = *s.invert();
+            let u1 = z * s_inv;
+            let u2 = *r * s_inv;
+
+            let x = (ProjectivePoint::GENERATOR * u1 + ProjectivePoint::from(negated) * -u2)
+                .to_affine()
+                .x();
+            assert_eq!(<Scalar as Reduce<U256>>::from_be_bytes_reduced(x), *r);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod multisig {
+        use crate::{
+            ecdsa::{signature::Signer, verify_multisig, Signature, SigningKey, VerifyingKey},
+            Scalar,
+        };
+        use elliptic_curve::{bigint::U256, ops::Reduce, rand_core::OsRng};
+        use sha2::{Digest, Sha256};
+
+        #[test]
+        fn threshold_with_invalid() {
+            let msg = b"attestation";
+            let z = <Scalar as Reduce<U256>>::from_be_bytes_reduced(Sha256::digest(msg));
+
+            let mut items = [0u8; 4].map(|_| {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let signature: Signature = signing_key.sign(msg);
+                (VerifyingKey::from(&signing_key), signature)
+            });
+
+            assert!(verify_multisig(&z, &items).is_ok());
+            assert!(verify_multisig(&z, &[]).is_err());
+
+            // Replace the last signature with one over a different message
+            let signing_key = SigningKey::random(&mut OsRng);
+            items[3] = (
+                VerifyingKey::from(&signing_key),
+                signing_key.sign(b"something else"),
+            );
+            assert!(verify_multisig(&z, &items).is_err());
+        }
+    }
+
+    mod any {
+        use crate::ecdsa::{signature::Signer, verify_any, Signature, SigningKey, VerifyingKey};
+        use elliptic_curve::rand_core::OsRng;
+
+        #[test]
+        fn matching_key_index() {
+            let msg = b"rotated keys";
+            let signing_keys = [0u8; 4].map(|_| SigningKey::random(&mut OsRng));
+            let keys = signing_keys.clone().map(|sk| VerifyingKey::from(&sk));
+
+            for (i, signing_key) in signing_keys.iter().enumerate() {
+                let signature: Signature = signing_key.sign(msg);
+                assert_eq!(verify_any(&keys, msg, &signature).unwrap(), i);
+                assert!(verify_any(&keys, b"other message", &signature).is_err());
+            }
+
+            let signature: Signature = SigningKey::random(&mut OsRng).sign(msg);
+            assert!(verify_any(&keys, msg, &signature).is_err());
+            assert!(verify_any(&[], msg, &signature).is_err());
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "sha256"))]
+    mod prefix {
+        use crate::ecdsa::{
+            recoverable,
+            signature::{Signer, Verifier},
+            Signature, SigningKey,
+        };
+        use elliptic_curve::rand_core::OsRng;
+
+        const PREFIX: &[u8] = b"example.com signed message v1\x00";
+
+        #[test]
+        fn matches_full_message() {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let verifying_key = signing_key.verifying_key();
+            let verifier = verifying_key.verifier_with_prefix(PREFIX);
+
+            for suffix in [&b""[..], b"a", b"suffix", &[0x5a; 200]] {
+                let msg = [PREFIX, suffix].concat();
+                let signature: Signature = signing_key.sign(&msg);
+                let recoverable: recoverable::Signature = signing_key.sign(&msg);
+
+                assert!(verifying_key.verify(&msg, &signature).is_ok());
+                assert!(verifier.verify_suffix(suffix, &signature).is_ok());
+                assert!(verifier.verify_suffix(suffix, &recoverable).is_ok());
+                assert!(verifier.verify_suffix(b"other", &signature).is_err());
+            }
+
+            // The prefix state is reused, not consumed
+            let signature: Signature = signing_key.sign(&[PREFIX, b"x"].concat());
+            assert!(verifier.verify_suffix(b"x", &signature).is_ok());
+            assert!(verifier.verify_suffix(b"x", &signature).is_ok());
+
+            // The message must actually start with the prefix
+            let unprefixed: Signature = signing_key.sign(b"x");
+            assert!(verifier.verify_suffix(b"x", &unprefixed).is_err());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod reader {
+        use crate::ecdsa::{signature::Signer, verify_reader, Signature, SigningKey};
+        use elliptic_curve::rand_core::OsRng;
+        use std::{
+            io::{self, Cursor, Read},
+            vec::Vec,
+        };
+
+        /// Reader which always fails.
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::Other, "read failed"))
+            }
+        }
+
+        #[test]
+        fn large_cursor() {
+            let signing_key = SigningKey::random(&mut OsRng);
+            let verifying_key = signing_key.verifying_key();
+            let msg: Vec<u8> = (0..(4 << 20)).map(|i: u32| i as u8).collect();
+            let signature: Signature = signing_key.sign(&msg);
+
+            assert!(verify_reader(&verifying_key, &signature, Cursor::new(&msg))
+                .unwrap()
+                .is_ok());
+
+            let truncated = Cursor::new(&msg[..msg.len() - 1]);
+            assert!(verify_reader(&verifying_key, &signature, truncated)
+                .unwrap()
+                .is_err());
+
+            assert!(verify_reader(&verifying_key, &signature, Failing).is_err());
+        }
+    }
+
+    mod prehash {
+        use crate::ecdsa::{
+            signature::{Signature as _, Signer},
+            verify_prehash, P256Error, Signature, SigningKey, VerifyingKey,
+        };
+        use elliptic_curve::rand_core::OsRng;
+        use sha2::{Digest, Sha256};
+
+        #[test]
+        fn error_paths() {
+            let msg = b"ffi";
+            let msg_hash: [u8; 32] = Sha256::digest(msg).into();
+            let signing_key = SigningKey::random(&mut OsRng);
+            let pk = VerifyingKey::from(&signing_key).to_bytes();
+            let signature: Signature = signing_key.sign(msg);
+            let sig = signature.as_bytes();
+
+            assert_eq!(verify_prehash(pk.as_ref(), &msg_hash, sig), Ok(()));
+
+            // Public key
+            assert_eq!(
+                verify_prehash(&pk[1..], &msg_hash, sig),
+                Err(P256Error::InvalidEncoding)
+            );
+            assert_eq!(
+                verify_prehash(&[0], &msg_hash, sig),
+                Err(P256Error::Identity)
+            );
+            let mut bad_pk = [0xff; 33];
+            bad_pk[0] = 0x02;
+            assert_eq!(
+                verify_prehash(&bad_pk, &msg_hash, sig),
+                Err(P256Error::CoordinateOutOfRange)
+            );
+
+            // Signature
+            assert_eq!(
+                verify_prehash(pk.as_ref(), &msg_hash, &sig[..63]),
+                Err(P256Error::InvalidSignatureLength)
+            );
+            let mut bad_sig = [0u8; 64];
+            bad_sig.copy_from_slice(sig);
+            bad_sig[..32].fill(0xff);
+            assert_eq!(
+                verify_prehash(pk.as_ref(), &msg_hash, &bad_sig),
+                Err(P256Error::InvalidR)
+            );
+            bad_sig.copy_from_slice(sig);
+            bad_sig[32..].fill(0);
+            assert_eq!(
+                verify_prehash(pk.as_ref(), &msg_hash, &bad_sig),
+                Err(P256Error::InvalidS)
+            );
+
+            // Verification
+            let other_hash: [u8; 32] = Sha256::digest(b"other").into();
+            assert_eq!(
+                verify_prehash(pk.as_ref(), &other_hash, sig),
+                Err(P256Error::VerificationFailed)
+            );
+        }
+    }
+
+    mod digest_sizes {
+        use crate::ecdsa::{signature::DigestVerifier, verify::bits2field, Signature, SigningKey};
+        use hex_literal::hex;
+        use sha2::{Digest, Sha224, Sha384, Sha512};
+
+        /// RFC6979 § A.2.5 private key.
+        const SECRET_KEY: [u8; 32] =
+            hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+        /// RFC6979 § A.2.5 signature of "sample" using SHA-224.
+        const SHA224_SIG: [u8; 64] = hex!(
+            "53B2FFF5D1752B2C689DF257C04C40A587FABABB3F6FC2702F1343AF7CA9AA3F
+             B9AFB64FDC03DC1A131C7D2386D11E349F070AA432A4ACC918BEA988BF75C74C"
+        );
+
+        /// RFC6979 § A.2.5 signature of "sample" using SHA-384.
+        const SHA384_SIG: [u8; 64] = hex!(
+            "0EAFEA039B20E9B42309FB1D89E213057CBF973DC0CFC8F129EDDDC800EF7719
+             4861F0491E6998B9455193E34E7B0D284DDD7149A74B95B9261F13ABDE940954"
+        );
+
+        #[test]
+        fn rfc6979_vectors() {
+            let verifying_key = SigningKey::from_bytes(&SECRET_KEY).unwrap().verifying_key();
+            let sha224_sig = Signature::try_from(&SHA224_SIG[..]).unwrap();
+            let sha384_sig = Signature::try_from(&SHA384_SIG[..]).unwrap();
+
+            assert!(verifying_key
+                .verify_digest(Sha224::new_with_prefix(b"sample"), &sha224_sig)
+                .is_ok());
+            assert!(verifying_key
+                .verify_digest(Sha384::new_with_prefix(b"sample"), &sha384_sig)
+                .is_ok());
+
+            // The digest function is part of what gets verified
+            assert!(verifying_key
+                .verify_digest(Sha384::new_with_prefix(b"sample"), &sha224_sig)
+                .is_err());
+            assert!(verifying_key
+                .verify_digest(Sha512::new_with_prefix(b"sample"), &sha384_sig)
+                .is_err());
+
+            let precomputed = verifying_key.precompute();
+            assert!(precomputed
+                .verify_digest(Sha224::new_with_prefix(b"sample"), &sha224_sig)
+                .is_ok());
+            assert!(precomputed
+                .verify_digest(Sha384::new_with_prefix(b"sample"), &sha384_sig)
+                .is_ok());
+        }
+
+        #[test]
+        fn message_representatives() {
+            let digest = hex!(
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f
+                 202122232425262728292a2b2c2d2e2f"
+            );
+
+            // Truncated to the leftmost 256 bits
+            assert_eq!(bits2field(&digest)[..], digest[..32]);
+
+            // Zero-extended on the left
+            let z = bits2field(&digest[..28]);
+            assert_eq!(z[..4], [0u8; 4]);
+            assert_eq!(z[4..], digest[..28]);
+        }
+    }
+
+    mod precomputed {
+        use crate::{
+            ecdsa::{
+                signature::{DigestVerifier, Verifier},
+                Signature, VerifyingKey,
+            },
+            test_vectors::ecdsa::ECDSA_TEST_VECTORS,
+            EncodedPoint,
+        };
+        use elliptic_curve::generic_array::GenericArray;
+        use sha2::{Digest, Sha256};
+
+        #[test]
+        fn matches_verifying_key() {
+            for vector in ECDSA_TEST_VECTORS {
+                let verifying_key =
+                    VerifyingKey::from_encoded_point(&EncodedPoint::from_affine_coordinates(
+                        GenericArray::from_slice(vector.q_x),
+                        GenericArray::from_slice(vector.q_y),
+                        false,
+                    ))
+                    .unwrap();
+                let precomputed = verifying_key.precompute();
+                assert_eq!(precomputed.verifying_key(), &verifying_key);
+
+                let signature = Signature::from_scalars(
+                    GenericArray::clone_from_slice(vector.r),
+                    GenericArray::clone_from_slice(vector.s),
+                )
+                .unwrap();
+
+                for msg in [&b"test"[..], vector.m] {
+                    let digest = Sha256::new_with_prefix(msg);
+                    assert_eq!(
+                        precomputed
+                            .verify_digest(digest.clone(), &signature)
+                            .is_ok(),
+                        verifying_key.verify_digest(digest, &signature).is_ok()
+                    );
+                }
+
+                assert_eq!(
+                    precomputed.verify(vector.m, &signature).is_ok(),
+                    verifying_key.verify(vector.m, &signature).is_ok()
+                );
+            }
+        }
+
+        #[test]
+        fn verify_many() {
+            use crate::ecdsa::{signature::Signer, SigningKey};
+            use elliptic_curve::rand_core::OsRng;
+
+            let signing_key = SigningKey::random(&mut OsRng);
+            let precomputed = signing_key.verifying_key().precompute();
+
+            for i in 0u8..8 {
+                let signature: Signature = signing_key.sign(&[i]);
+                assert!(precomputed.verify(&[i], &signature).is_ok());
+                assert!(precomputed.verify(&[i + 1], &signature).is_err());
+            }
+        }
+    }
 }
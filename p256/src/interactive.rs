@@ -0,0 +1,160 @@
+//! Scalar operations for interactive two-round Schnorr signing with additive
+//! key shares.
+//!
+//! The signing key is the sum of the parties' key shares `d_i`, and the
+//! public key `P` the sum of their public shares `d_i·G`. In round 1 every
+//! party draws a fresh [`NoncePair`] and commits to its
+//! [`public_nonce`][`NoncePair::public_nonce`], e.g. by broadcasting a hash
+//! of it. In round 2 the parties reveal their points, check them against the
+//! commitments, add them up into the combined `R` and each compute a
+//! [`partial_sign`][`NoncePair::partial_sign`]ature with their key share. The
+//! sum of the partial signatures is a signature `(R, s)` for `P`, which
+//! [`verify`] checks.
+//!
+//! ECDSA is not linear in its nonce, so additive nonce shares can't be
+//! combined into an ECDSA signature without considerably more machinery.
+//! These primitives instead use the Schnorr equation `s = k + e·d`, where
+//! the challenge `e` is computed by [`challenge`].
+//!
+//! This is plain additive-key Schnorr, *not* MuSig: public key shares are
+//! summed as-is, without key aggregation coefficients, so nothing stops a
+//! party from choosing its share as a function of the others' (a "rogue key"
+//! attack).
+//!
+//! ⚠️ These are only the curve-specific building blocks, not a complete
+//! protocol: committing to (and checking) nonce points before revealing
+//! them, and making sure every public key share is honestly generated
+//! (e.g. with a proof of possession), are up to the caller. Skipping either
+//! allows other parties to forge signatures.
+//!
+//! ```
+//! # #[cfg(feature = "interactive")]
+//! # {
+//! use p256::{interactive::{self, NoncePair}, FieldBytes, SecretKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! let (d1, d2) = (SecretKey::random(&mut OsRng), SecretKey::random(&mut OsRng));
+//! let public_key = (d1.public_key().to_projective() + d2.public_key().to_projective())
+//!     .to_affine();
+//! let z = FieldBytes::from([0x42; 32]);
+//!
+//! // Round 1: generate nonces and exchange commitments to `r1` and `r2`
+//! let (k1, k2) = (NoncePair::generate(&mut OsRng), NoncePair::generate(&mut OsRng));
+//! let (r1, r2) = (k1.public_nonce(), k2.public_nonce());
+//!
+//! // Round 2: reveal `r1` and `r2` and sign, consuming the nonces
+//! let r = r1 + r2;
+//! let s1 = k1.partial_sign(&*d1.to_nonzero_scalar(), &public_key, &z, &r);
+//! let s2 = k2.partial_sign(&*d2.to_nonzero_scalar(), &public_key, &z, &r);
+//!
+//! assert!(interactive::verify(&public_key, &z, &r, &(s1 + s2)));
+//! # }
+//! ```
+
+use crate::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, Scalar, U256};
+use core::fmt::{self, Debug};
+use elliptic_curve::{
+    group::{Group, GroupEncoding},
+    ops::{LinearCombination, Reduce},
+    rand_core::{CryptoRng, RngCore},
+    zeroize::{Zeroize, ZeroizeOnDrop},
+};
+use sha2::{Digest, Sha256};
+
+/// Per-signature nonce of a single party: a secret nonce `k` along with its
+/// public point `k·G`.
+///
+/// The secret nonce is zeroized on drop, and is only ever used by
+/// [`NoncePair::partial_sign`], which consumes the pair so that the same
+/// nonce can't sign twice.
+pub struct NoncePair {
+    /// Secret nonce `k`.
+    secret: Scalar,
+
+    /// Public nonce point `k·G`.
+    public: ProjectivePoint,
+}
+
+impl NoncePair {
+    /// Generate a fresh secret nonce `k` along with its public point `k·G`.
+    pub fn generate(rng: &mut (impl CryptoRng + RngCore)) -> Self {
+        let k = NonZeroScalar::random(rng);
+
+        Self {
+            secret: *k,
+            public: ProjectivePoint::GENERATOR * *k,
+        }
+    }
+
+    /// Returns the public nonce point `k·G`, which is what each party commits
+    /// to in round 1.
+    pub fn public_nonce(&self) -> ProjectivePoint {
+        self.public
+    }
+
+    /// Compute this party's partial signature `k + e·d` from its key share
+    /// `d`, the combined public key `P`, the message representative `z` and
+    /// the combined nonce point `R` (the sum of every party's public nonce).
+    ///
+    /// The full signature's `s` is the sum of every party's partial
+    /// signature.
+    pub fn partial_sign(
+        self,
+        d: &Scalar,
+        public_key: &AffinePoint,
+        z: &FieldBytes,
+        combined_r: &ProjectivePoint,
+    ) -> Scalar {
+        self.secret + challenge(combined_r, public_key, z) * d
+    }
+}
+
+impl Debug for NoncePair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NoncePair")
+            .field("public", &self.public)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for NoncePair {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for NoncePair {}
+
+/// Compute the Schnorr challenge `e = SHA-256(R || P || z) mod n` for the
+/// combined nonce point `R`, the combined public key `P` (both SEC1
+/// compressed) and the 32-byte message representative `z`.
+///
+/// Committing to `P` binds signatures to the key they were made for.
+pub fn challenge(combined_r: &ProjectivePoint, public_key: &AffinePoint, z: &FieldBytes) -> Scalar {
+    let digest = Sha256::new()
+        .chain_update(combined_r.to_bytes())
+        .chain_update(public_key.to_bytes())
+        .chain_update(z)
+        .finalize();
+
+    <Scalar as Reduce<U256>>::from_be_bytes_reduced(digest)
+}
+
+/// Verify a signature `(R, s)` over the message representative `z`, i.e.
+/// check `s·G = R + e·P`, where `P` is the sum of the parties' public keys.
+///
+/// Rejects the identity as either `R` or `P`.
+pub fn verify(
+    public_key: &AffinePoint,
+    z: &FieldBytes,
+    combined_r: &ProjectivePoint,
+    s: &Scalar,
+) -> bool {
+    if bool::from(public_key.is_identity() | combined_r.is_identity()) {
+        return false;
+    }
+
+    let e = challenge(combined_r, public_key, z);
+    let public_key = ProjectivePoint::from(public_key);
+    ProjectivePoint::lincomb(&ProjectivePoint::GENERATOR, s, &public_key, &-e) == *combined_r
+}
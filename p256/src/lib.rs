@@ -4,7 +4,8 @@
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo.svg"
 )]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "capi"), forbid(unsafe_code))]
+#![cfg_attr(feature = "capi", deny(unsafe_code))]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 #![doc = include_str!("../README.md")]
 
@@ -16,12 +17,41 @@
 //! - [`AffinePoint`]
 //! - [`Scalar`]
 //! - [`ecdsa::VerifyingKey`]
+//! - [`ecdsa::recoverable::Signature`]
 //!
 //! Please see type-specific documentation for more information.
+//!
+//! ## ⚠️ `dangerous-test-only`
+//!
+//! This feature exposes the ECDSA nonce of a signature, which reveals the
+//! signing key to anyone who sees both. It exists **solely** for security
+//! research and fault-injection tests: never enable it in a build which
+//! handles real keys, and make sure no dependency enables it either. It is
+//! excluded from the documented feature set on docs.rs.
+//!
+//! Enabling the feature alone is a compile error: the build must also opt in
+//! with `RUSTFLAGS="--cfg p256_dangerous_test_only"` (and the same
+//! `RUSTDOCFLAGS` for doctests), so that a dependency can't turn it on behind
+//! your back.
+
+#[cfg(all(feature = "dangerous-test-only", not(p256_dangerous_test_only)))]
+compile_error!(
+    "the `dangerous-test-only` feature exposes signing nonces; \
+     build with `RUSTFLAGS=\"--cfg p256_dangerous_test_only\"` to enable it"
+);
+
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
+#[cfg(feature = "capi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "capi")))]
+pub mod capi;
+
 #[cfg(feature = "ecdh")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdh")))]
 pub mod ecdh;
@@ -30,16 +60,29 @@ pub mod ecdh;
 #[cfg_attr(docsrs, doc(cfg(feature = "ecdsa-core")))]
 pub mod ecdsa;
 
+#[cfg(feature = "interactive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "interactive")))]
+pub mod interactive;
+
+#[cfg(feature = "oprf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oprf")))]
+pub mod oprf;
+
 #[cfg(any(feature = "test-vectors", test))]
 #[cfg_attr(docsrs, doc(cfg(feature = "test-vectors")))]
 pub mod test_vectors;
 
+#[cfg(feature = "vrf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vrf")))]
+pub mod vrf;
+
 pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
 pub use arithmetic::{
+    point::{HybridPoint, UncompressedPoint, ValidatedCompressedPoint},
     scalar::{blinded::BlindedScalar, Scalar},
-    AffinePoint, ProjectivePoint,
+    AffinePoint, PrecomputedPoint, ProjectivePoint,
 };
 
 #[cfg(feature = "expose-field")]
@@ -121,7 +164,28 @@ impl pkcs8::AssociatedOid for NistP256 {
     const OID: pkcs8::ObjectIdentifier = pkcs8::ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
 }
 
+#[cfg(feature = "pkcs8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs8")))]
+impl NistP256 {
+    /// OID of the `prime256v1` (a.k.a. `secp256r1`) named curve.
+    ///
+    /// Same as [`pkcs8::AssociatedOid::OID`], without needing the trait in
+    /// scope.
+    pub const OID: pkcs8::ObjectIdentifier = <Self as pkcs8::AssociatedOid>::OID;
+
+    /// Does `oid` identify this curve?
+    ///
+    /// Handy for routing keys to the right curve in code which supports
+    /// several of them.
+    pub fn matches_oid(oid: &pkcs8::ObjectIdentifier) -> bool {
+        *oid == Self::OID
+    }
+}
+
 /// Compressed SEC1-encoded NIST P-256 curve point.
+///
+/// This is a plain byte array, which may not hold a valid encoding; with the
+/// `arithmetic` feature, `ValidatedCompressedPoint` only holds valid points.
 pub type CompressedPoint = GenericArray<u8, U33>;
 
 /// NIST P-256 field element serialized as bytes.
@@ -0,0 +1,230 @@
+//! Oblivious Pseudorandom Function (OPRF).
+//!
+//! Implements the base (`OPRF`) mode of the `P256-SHA256` ciphersuite from
+//! [RFC 9497]. A client blinds its input, a server evaluates the blinded
+//! input with its secret key without learning the input, and the client
+//! unblinds the result to obtain the PRF output.
+//!
+//! Base mode carries no proof, so the client can't tell which key the
+//! server used.
+//!
+//! ```
+//! # #[cfg(feature = "oprf")]
+//! # {
+//! use p256::{oprf, SecretKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Server
+//! let secret_key = SecretKey::random(&mut OsRng);
+//!
+//! // Client
+//! let (blind, blinded_element) = oprf::blind(b"input", &mut OsRng).unwrap();
+//!
+//! // Server
+//! let evaluated_element = oprf::blind_evaluate(&secret_key, &blinded_element);
+//!
+//! // Client
+//! let output = oprf::finalize(b"input", &blind, &evaluated_element).unwrap();
+//! # }
+//! ```
+//!
+//! [RFC 9497]: https://www.rfc-editor.org/rfc/rfc9497
+
+use crate::{
+    AffinePoint, NistP256, NonZeroScalar, ProjectivePoint, SecretKey, ValidatedCompressedPoint,
+};
+use elliptic_curve::{
+    hash2curve::{ExpandMsgXmd, GroupDigest},
+    ops::Invert,
+    rand_core::{CryptoRng, RngCore},
+    Error, Field, Result,
+};
+use sha2::{Digest, Sha256};
+
+/// `"HashToGroup-" || contextString` for `P256-SHA256` in base mode.
+const HASH_TO_GROUP_DST: &[u8] = b"HashToGroup-OPRFV1-\x00-P256-SHA256";
+
+/// `"DeriveKeyPair" || contextString` for `P256-SHA256` in base mode.
+const DERIVE_KEY_PAIR_DST: &[u8] = b"DeriveKeyPairOPRFV1-\x00-P256-SHA256";
+
+/// OPRF output, a SHA-256 digest.
+pub type Output = [u8; 32];
+
+/// Blind an `input`, returning the blinding scalar (which the client keeps
+/// for [`finalize`]) along with the blinded element to send to the server.
+///
+/// Fails (with negligible probability) if `input` hashes to the identity.
+pub fn blind(
+    input: &[u8],
+    rng: &mut (impl CryptoRng + RngCore),
+) -> Result<(NonZeroScalar, ValidatedCompressedPoint)> {
+    let blind = NonZeroScalar::random(rng);
+    blind_with(input, &blind).map(|blinded_element| (blind, blinded_element))
+}
+
+/// Blind an `input` with the given blinding scalar.
+///
+/// The blind must be uniformly random and never reused: prefer [`blind`],
+/// which generates one.
+pub fn blind_with(input: &[u8], blind: &NonZeroScalar) -> Result<ValidatedCompressedPoint> {
+    let input_element = hash_to_group(input)?;
+    ValidatedCompressedPoint::try_from((input_element * **blind).to_affine())
+}
+
+/// Evaluate a client's blinded element with the server's secret key.
+pub fn blind_evaluate(
+    secret_key: &SecretKey,
+    blinded_element: &ValidatedCompressedPoint,
+) -> ValidatedCompressedPoint {
+    let point = ProjectivePoint::from(AffinePoint::from(blinded_element));
+    let evaluated_element = point * *secret_key.to_nonzero_scalar();
+
+    // Both factors are nonzero and the group has prime order
+    ValidatedCompressedPoint::try_from(evaluated_element.to_affine())
+        .expect("evaluated element is identity")
+}
+
+/// Unblind the server's evaluated element and compute the OPRF output for
+/// `input`.
+///
+/// Fails if `input` is longer than 65535 bytes.
+pub fn finalize(
+    input: &[u8],
+    blind: &NonZeroScalar,
+    evaluated_element: &ValidatedCompressedPoint,
+) -> Result<Output> {
+    let input_len = u16::try_from(input.len()).map_err(|_| Error)?;
+    let point = ProjectivePoint::from(AffinePoint::from(evaluated_element));
+    let unblinded_element =
+        ValidatedCompressedPoint::try_from((point * *Invert::invert(blind)).to_affine())?;
+    let element_len = unblinded_element.as_bytes().len() as u16;
+
+    Ok(Sha256::new()
+        .chain_update(input_len.to_be_bytes())
+        .chain_update(input)
+        .chain_update(element_len.to_be_bytes())
+        .chain_update(unblinded_element)
+        .chain_update(b"Finalize")
+        .finalize()
+        .into())
+}
+
+/// Deterministically derive a server key from a `seed` and public `info`
+/// (RFC 9497 Section 3.2.1).
+pub fn derive_key_pair(seed: &[u8; 32], info: &[u8]) -> Result<SecretKey> {
+    let info_len = u16::try_from(info.len()).map_err(|_| Error)?;
+
+    for counter in 0..=u8::MAX {
+        let scalar = NistP256::hash_to_scalar::<ExpandMsgXmd<Sha256>>(
+            &[seed, &info_len.to_be_bytes(), info, &[counter]],
+            DERIVE_KEY_PAIR_DST,
+        )?;
+
+        if !bool::from(scalar.is_zero()) {
+            return SecretKey::from_be_bytes(&scalar.to_bytes());
+        }
+    }
+
+    Err(Error)
+}
+
+/// `HashToGroup` with the ciphersuite's domain separation tag.
+fn hash_to_group(input: &[u8]) -> Result<ProjectivePoint> {
+    NistP256::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[input], HASH_TO_GROUP_DST)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blind, blind_evaluate, blind_with, derive_key_pair, finalize};
+    use crate::{NonZeroScalar, SecretKey, ValidatedCompressedPoint};
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+
+    struct TestVector {
+        input: &'static [u8],
+        blind: [u8; 32],
+        blinded_element: [u8; 33],
+        evaluation_element: [u8; 33],
+        output: [u8; 32],
+    }
+
+    /// `P256-SHA256` OPRF mode key from RFC 9497 Appendix A.3.1.
+    const SEED: [u8; 32] = [0xa3; 32];
+    const KEY_INFO: &[u8] = b"test key";
+    const SK_SM: [u8; 32] =
+        hex!("159749d750713afe245d2d39ccfaae8381c53ce92d098a9375ee70739c7ac0bf");
+
+    /// `P256-SHA256` OPRF mode test vectors from RFC 9497 Appendix A.3.1.
+    const TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            input: &hex!("00"),
+            blind: hex!("3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364"),
+            blinded_element: hex!(
+                "03723a1e5c09b8b9c18d1dcbca29e8007e95f14f4732d9346d490ffc195110368d"
+            ),
+            evaluation_element: hex!(
+                "030de02ffec47a1fd53efcdd1c6faf5bdc270912b8749e783c7ca75bb412958832"
+            ),
+            output: hex!("a0b34de5fa4c5b6da07e72af73cc507cceeb48981b97b7285fc375345fe495dd"),
+        },
+        TestVector {
+            input: &hex!("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a"),
+            blind: hex!("3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364"),
+            blinded_element: hex!(
+                "03cc1df781f1c2240a64d1c297b3f3d16262ef5d4cf102734882675c26231b0838"
+            ),
+            evaluation_element: hex!(
+                "03a0395fe3828f2476ffcd1f4fe540e5a8489322d398be3c4e5a869db7fcb7c52c"
+            ),
+            output: hex!("c748ca6dd327f0ce85f4ae3a8cd6d4d5390bbb804c9e12dcf94f853fece3dcce"),
+        },
+    ];
+
+    #[test]
+    fn rfc9497_test_vectors() {
+        let secret_key = derive_key_pair(&SEED, KEY_INFO).unwrap();
+        assert_eq!(secret_key.to_be_bytes().as_slice(), SK_SM);
+
+        for vector in TEST_VECTORS {
+            let blind = NonZeroScalar::from_repr(vector.blind.into()).unwrap();
+
+            let blinded_element = blind_with(vector.input, &blind).unwrap();
+            assert_eq!(blinded_element.as_bytes(), &vector.blinded_element);
+
+            let evaluation_element = blind_evaluate(&secret_key, &blinded_element);
+            assert_eq!(evaluation_element.as_bytes(), &vector.evaluation_element);
+
+            let output = finalize(vector.input, &blind, &evaluation_element).unwrap();
+            assert_eq!(output, vector.output);
+        }
+    }
+
+    #[test]
+    fn random_blind() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let evaluate = |input: &[u8]| {
+            let (blind, blinded_element) = blind(input, &mut OsRng).unwrap();
+            let evaluation_element = blind_evaluate(&secret_key, &blinded_element);
+            (
+                blinded_element,
+                finalize(input, &blind, &evaluation_element).unwrap(),
+            )
+        };
+
+        // The output only depends on the input, whereas the blinded element
+        // the server sees is fresh every time
+        let (blinded_a, output_a) = evaluate(b"input");
+        let (blinded_b, output_b) = evaluate(b"input");
+        assert_eq!(output_a, output_b);
+        assert_ne!(blinded_a, blinded_b);
+        assert_ne!(evaluate(b"other").1, output_a);
+    }
+
+    #[test]
+    fn finalize_rejects_long_input() {
+        let blind = NonZeroScalar::random(&mut OsRng);
+        let element = ValidatedCompressedPoint::try_from(crate::AffinePoint::GENERATOR).unwrap();
+        assert!(finalize(&[0; 0x1_0000], &blind, &element).is_err());
+        assert!(finalize(&[0; 0xffff], &blind, &element).is_ok());
+    }
+}
@@ -0,0 +1,292 @@
+//! Elliptic Curve Verifiable Random Function (ECVRF).
+//!
+//! Implements the `ECVRF-P256-SHA256-TAI` ciphersuite of [RFC 9381]
+//! (previously `draft-irtf-cfrg-vrf`), which hashes inputs to the curve
+//! with the "try-and-increment" method and derives nonces per RFC 6979.
+//!
+//! The holder of a [`SecretKey`] computes a pseudorandom output `beta` for
+//! an input `alpha`, along with a proof `pi` which anyone holding the
+//! corresponding [`PublicKey`] can use to check that `beta` is *the* output
+//! for `alpha`.
+//!
+//! ```
+//! # #[cfg(feature = "vrf")]
+//! # {
+//! use p256::{vrf, SecretKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! let secret_key = SecretKey::random(&mut OsRng);
+//! let (beta, pi) = vrf::prove(&secret_key, b"sample");
+//!
+//! assert_eq!(vrf::verify(&secret_key.public_key(), b"sample", &pi), Some(beta));
+//! assert_eq!(vrf::verify(&secret_key.public_key(), b"other", &pi), None);
+//! # }
+//! ```
+//!
+//! [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+
+use crate::{
+    AffinePoint, FieldBytes, NistP256, ProjectivePoint, PublicKey, Scalar, SecretKey, U256,
+};
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    group::GroupEncoding,
+    ops::{LinearCombination, Reduce},
+    sec1::{CompressedPoint, ToEncodedPoint},
+    Curve, PrimeField,
+};
+use sha2::{Digest, Sha256};
+
+/// Ciphersuite identifier for `ECVRF-P256-SHA256-TAI`.
+const SUITE: u8 = 0x01;
+
+/// Size of a SEC1 compressed point in bytes (`ptLen`).
+const POINT_SIZE: usize = 33;
+
+/// Size of the challenge in bytes (`cLen`).
+const CHALLENGE_SIZE: usize = 16;
+
+/// Size of a scalar in bytes (`qLen`).
+const SCALAR_SIZE: usize = 32;
+
+/// SEC1 compressed encoding of a point (`point_to_string`).
+type PointBytes = CompressedPoint<NistP256>;
+
+/// Size of an encoded proof in bytes.
+pub const PROOF_SIZE: usize = POINT_SIZE + CHALLENGE_SIZE + SCALAR_SIZE;
+
+/// VRF proof (`pi`): the encoded point `Gamma`, challenge `c`, and
+/// response `s`.
+pub type Proof = [u8; PROOF_SIZE];
+
+/// VRF output (`beta`), a SHA-256 digest.
+pub type Output = [u8; 32];
+
+/// Compute the VRF output for `alpha` along with a proof of its
+/// correctness.
+///
+/// This is deterministic: the same key and input always produce the same
+/// output and proof.
+pub fn prove(secret_key: &SecretKey, alpha: &[u8]) -> (Output, Proof) {
+    let x = *secret_key.to_nonzero_scalar();
+    let public_key = secret_key.public_key();
+    let pk_string = public_key.as_affine().to_bytes();
+
+    // Finding no point in 256 attempts happens with probability ~2^-256
+    let h = encode_to_curve(&pk_string, alpha).expect("try-and-increment failed");
+    let h_string = h.to_bytes();
+    let gamma = (h * x).to_affine();
+
+    let k = nonce(&x, &h_string);
+    let u = (ProjectivePoint::GENERATOR * k).to_affine();
+    let v = (h * k).to_affine();
+
+    let c = challenge(public_key.as_affine(), &h, &gamma, &u, &v);
+    let s = k + challenge_scalar(&c) * x;
+
+    let mut pi = [0u8; PROOF_SIZE];
+    pi[..POINT_SIZE].copy_from_slice(&gamma.to_bytes());
+    pi[POINT_SIZE..POINT_SIZE + CHALLENGE_SIZE].copy_from_slice(&c);
+    pi[POINT_SIZE + CHALLENGE_SIZE..].copy_from_slice(&s.to_bytes());
+
+    (proof_to_hash(&gamma), pi)
+}
+
+/// Verify that `pi` proves the VRF output for `alpha` under `public_key`,
+/// returning that output if so.
+pub fn verify(public_key: &PublicKey, alpha: &[u8], pi: &Proof) -> Option<Output> {
+    let (gamma, c, s) = decode_proof(pi)?;
+    let y = public_key.as_affine();
+    let h = encode_to_curve(&y.to_bytes(), alpha)?;
+
+    let c_scalar = challenge_scalar(&c);
+    let u = ProjectivePoint::lincomb(
+        &ProjectivePoint::GENERATOR,
+        &s,
+        &ProjectivePoint::from(*y),
+        &-c_scalar,
+    );
+    let v = ProjectivePoint::lincomb(
+        &ProjectivePoint::from(h),
+        &s,
+        &ProjectivePoint::from(gamma),
+        &-c_scalar,
+    );
+
+    if challenge(y, &h, &gamma, &u.to_affine(), &v.to_affine()) == c {
+        Some(proof_to_hash(&gamma))
+    } else {
+        None
+    }
+}
+
+/// Derive the VRF output from a proof without verifying it.
+///
+/// Only use this on proofs which have already been verified (or which were
+/// just produced by [`prove`]).
+pub fn proof_to_output(pi: &Proof) -> Option<Output> {
+    decode_proof(pi).map(|(gamma, ..)| proof_to_hash(&gamma))
+}
+
+/// `ECVRF_encode_to_curve_try_and_increment` (RFC 9381 Section 5.4.1.1),
+/// salted with the encoded public key.
+fn encode_to_curve(pk_string: &PointBytes, alpha: &[u8]) -> Option<AffinePoint> {
+    (0..=u8::MAX).find_map(|ctr| {
+        let hash = Sha256::new()
+            .chain_update([SUITE, 0x01])
+            .chain_update(pk_string)
+            .chain_update(alpha)
+            .chain_update([ctr, 0x00])
+            .finalize();
+
+        let mut point = PointBytes::default();
+        point[0] = 0x02;
+        point[1..].copy_from_slice(&hash);
+        Option::from(AffinePoint::from_bytes(&point))
+    })
+}
+
+/// `ECVRF_nonce_generation_RFC6979` (RFC 9381 Section 5.4.2.1), using the
+/// encoded hash-to-curve point `h_string` as the message.
+fn nonce(x: &Scalar, h_string: &PointBytes) -> Scalar {
+    let h1 = <Scalar as Reduce<U256>>::from_be_bytes_reduced(Sha256::digest(h_string));
+    let k = rfc6979::generate_k::<Sha256, U256>(&x.0, &NistP256::ORDER, &h1.to_bytes(), &[]);
+    Scalar::from_be_bytes_reduced(k.to_be_byte_array())
+}
+
+/// `ECVRF_challenge_generation` (RFC 9381 Section 5.4.3).
+fn challenge(
+    y: &AffinePoint,
+    h: &AffinePoint,
+    gamma: &AffinePoint,
+    u: &AffinePoint,
+    v: &AffinePoint,
+) -> [u8; CHALLENGE_SIZE] {
+    let mut hasher = Sha256::new().chain_update([SUITE, 0x02]);
+
+    for point in [y, h, gamma, u, v] {
+        hasher.update(point.to_encoded_point(true));
+    }
+
+    let mut c = [0u8; CHALLENGE_SIZE];
+    c.copy_from_slice(&hasher.chain_update([0x00]).finalize()[..CHALLENGE_SIZE]);
+    c
+}
+
+/// Interpret a challenge as a scalar.
+fn challenge_scalar(c: &[u8; CHALLENGE_SIZE]) -> Scalar {
+    let mut bytes = FieldBytes::default();
+    bytes[SCALAR_SIZE - CHALLENGE_SIZE..].copy_from_slice(c);
+    Scalar::from_be_bytes_reduced(bytes)
+}
+
+/// `ECVRF_proof_to_hash` (RFC 9381 Section 5.2). P-256 has cofactor 1, so
+/// `Gamma` is hashed as-is.
+fn proof_to_hash(gamma: &AffinePoint) -> Output {
+    Sha256::new()
+        .chain_update([SUITE, 0x03])
+        .chain_update(gamma.to_bytes())
+        .chain_update([0x00])
+        .finalize()
+        .into()
+}
+
+/// `ECVRF_decode_proof` (RFC 9381 Section 5.4.4).
+fn decode_proof(pi: &Proof) -> Option<(AffinePoint, [u8; CHALLENGE_SIZE], Scalar)> {
+    let (gamma, rest) = pi.split_at(POINT_SIZE);
+    let (c, s) = rest.split_at(CHALLENGE_SIZE);
+
+    let gamma = Option::from(AffinePoint::from_bytes(PointBytes::from_slice(gamma)))?;
+    let s = Option::from(Scalar::from_repr(*FieldBytes::from_slice(s)))?;
+    Some((gamma, c.try_into().ok()?, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{proof_to_output, prove, verify, PROOF_SIZE};
+    use crate::{PublicKey, SecretKey};
+    use hex_literal::hex;
+
+    struct TestVector {
+        sk: [u8; 32],
+        pk: [u8; 33],
+        alpha: &'static [u8],
+        pi: [u8; PROOF_SIZE],
+        beta: [u8; 32],
+    }
+
+    /// `ECVRF-P256-SHA256-TAI` examples from RFC 9381 Appendix B.1.
+    const TEST_VECTORS: &[TestVector] = &[
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"),
+            alpha: b"sample",
+            pi: hex!(
+                "035b5c726e8c0e2c488a107c600578ee75cb702343c153cb1eb8dec77f4b5071b4"
+                "a53f0a46f018bc2c56e58d383f2305e0975972c26feea0eb122fe7893c15af376b"
+                "33edf7de17c6ea056d4d82de6bc02f"
+            ),
+            beta: hex!("a3ad7b0ef73d8fc6655053ea22f9bede8c743f08bbed3d38821f0e16474b505e"),
+        },
+        TestVector {
+            sk: hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"),
+            pk: hex!("0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"),
+            alpha: b"test",
+            pi: hex!(
+                "034dac60aba508ba0c01aa9be80377ebd7562c4a52d74722e0abae7dc3080ddb56"
+                "c19e067b15a8a8174905b13617804534214f935b94c2287f797e393eb0816969d8"
+                "64f37625b443f30f1a5a33f2b3c854"
+            ),
+            beta: hex!("a284f94ceec2ff4b3794629da7cbafa49121972671b466cab4ce170aa365f26d"),
+        },
+        TestVector {
+            sk: hex!("2ca1411a41b17b24cc8c3b089cfd033f1920202a6c0de8abb97df1498d50d2c8"),
+            pk: hex!("03596375e6ce57e0f20294fc46bdfcfd19a39f8161b58695b3ec5b3d16427c274d"),
+            alpha: b"Example using ECDSA key from Appendix L.4.2 of ANSI.X9-62-2005",
+            pi: hex!(
+                "03d03398bf53aa23831d7d1b2937e005fb0062cbefa06796579f2a1fc7e7b8c667"
+                "d091c00b0f5c3619d10ecea44363b5a599cadc5b2957e223fec62e81f7b4825fc7"
+                "99a771a3d7334b9186bdbee87316b1"
+            ),
+            beta: hex!("90871e06da5caa39a3c61578ebb844de8635e27ac0b13e829997d0d95dd98c19"),
+        },
+    ];
+
+    #[test]
+    fn rfc9381_test_vectors() {
+        for vector in TEST_VECTORS {
+            let secret_key = SecretKey::from_be_bytes(&vector.sk).unwrap();
+            let public_key = PublicKey::from_sec1_bytes(&vector.pk).unwrap();
+            assert_eq!(secret_key.public_key(), public_key);
+
+            let (beta, pi) = prove(&secret_key, vector.alpha);
+            assert_eq!(beta, vector.beta);
+            assert_eq!(pi, vector.pi);
+            assert_eq!(verify(&public_key, vector.alpha, &pi), Some(beta));
+            assert_eq!(proof_to_output(&pi), Some(beta));
+        }
+    }
+
+    #[test]
+    fn reject_invalid_proofs() {
+        let vector = &TEST_VECTORS[0];
+        let public_key = PublicKey::from_sec1_bytes(&vector.pk).unwrap();
+        let other_key = PublicKey::from_sec1_bytes(&TEST_VECTORS[2].pk).unwrap();
+
+        assert!(verify(&public_key, b"other", &vector.pi).is_none());
+        assert!(verify(&other_key, vector.alpha, &vector.pi).is_none());
+
+        // Flipping a bit anywhere in the proof breaks it
+        for i in 0..PROOF_SIZE {
+            let mut pi = vector.pi;
+            pi[i] ^= 1;
+            assert!(verify(&public_key, vector.alpha, &pi).is_none());
+        }
+
+        // `s` must be reduced
+        let mut pi = vector.pi;
+        pi[PROOF_SIZE - 32..].fill(0xff);
+        assert!(verify(&public_key, vector.alpha, &pi).is_none());
+        assert!(proof_to_output(&pi).is_none());
+    }
+}
@@ -2,7 +2,7 @@
 
 // TODO(tarcieri): point compaction support
 
-#![cfg(all(feature = "arithmetic"))]
+#![cfg(feature = "arithmetic")]
 
 use elliptic_curve::{
     group::{prime::PrimeCurveAffine, GroupEncoding},
@@ -90,7 +90,7 @@ fn compact_round_trip() {
 #[test]
 fn uncompact_to_compact() {
     let pubkey = EncodedPoint::from_bytes(UNCOMPACT_BASEPOINT).unwrap();
-    assert_eq!(false, pubkey.is_compact());
+    assert!(!pubkey.is_compact());
 
     let point = AffinePoint::from_encoded_point(&pubkey).unwrap();
     let res = point.to_compact_encoded_point().unwrap();
@@ -118,3 +118,208 @@ fn identity_encoding() {
             .is_identity()
     ))
 }
+
+#[test]
+fn identity_sec1_round_trip() {
+    let encoded = AffinePoint::IDENTITY.to_encoded_point(true);
+    assert_eq!(encoded.as_bytes(), &[0x00]);
+    assert_eq!(AffinePoint::IDENTITY.to_encoded_point(false), encoded);
+
+    let point = AffinePoint::from_encoded_point(&encoded).unwrap();
+    assert!(bool::from(point.is_identity()));
+
+    // Key types reject the identity even though it is a valid point
+    assert!(bool::from(
+        p256::PublicKey::from_encoded_point(&encoded).is_none()
+    ));
+    assert!(p256::PublicKey::from_sec1_bytes(&[0x00]).is_err());
+}
+
+#[test]
+fn decompress_never_returns_identity() {
+    use elliptic_curve::{subtle::Choice, DecompressPoint};
+
+    // The identity's internal coordinates are zero, but `x = 0` decompresses
+    // to a regular point since `b` is a square modulo `p`
+    let zero = p256::FieldBytes::default();
+
+    for y_is_odd in [Choice::from(0), Choice::from(1)] {
+        let point = AffinePoint::decompress(&zero, y_is_odd).unwrap();
+        assert!(!bool::from(point.is_identity()));
+        assert!(bool::from(point.is_on_curve()));
+        assert_eq!(point.to_encoded_point(false).x().unwrap(), &zero);
+    }
+
+    // Out of range x-coordinates are rejected rather than mapped to the
+    // identity
+    assert!(bool::from(
+        AffinePoint::decompress(&[0xff; 32].into(), Choice::from(0)).is_none()
+    ));
+
+    // Would-be identity encodings are rejected by the key path
+    let mut compressed_identity = [0u8; 33];
+    assert!(p256::PublicKey::from_sec1_bytes(&compressed_identity).is_err());
+    compressed_identity[0] = 0x02;
+    assert!(!bool::from(
+        p256::PublicKey::from_sec1_bytes(&compressed_identity)
+            .unwrap()
+            .as_affine()
+            .is_identity()
+    ));
+}
+
+#[test]
+fn coordinates() {
+    let (x, y) = AffinePoint::GENERATOR.coordinates().unwrap();
+    assert_eq!(&x[..], &UNCOMPRESSED_BASEPOINT[1..33]);
+    assert_eq!(&y[..], &UNCOMPRESSED_BASEPOINT[33..]);
+
+    let (neg_x, neg_y) = (-AffinePoint::GENERATOR).coordinates().unwrap();
+    assert_eq!(neg_x, x);
+    assert_ne!(neg_y, y);
+
+    assert!(AffinePoint::IDENTITY.coordinates().is_none());
+}
+
+#[test]
+fn is_on_curve() {
+    assert!(bool::from(AffinePoint::GENERATOR.is_on_curve()));
+    assert!(bool::from(AffinePoint::IDENTITY.is_on_curve()));
+    assert!(bool::from((-AffinePoint::GENERATOR).is_on_curve()));
+
+    let x = hex!("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296");
+    let y = hex!("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5");
+    let point = AffinePoint::from_coordinates_unchecked(&x.into(), &y.into()).unwrap();
+    assert_eq!(point, AffinePoint::GENERATOR);
+    assert!(bool::from(point.is_on_curve()));
+
+    // Tweak the least significant bit of each coordinate
+    let mut x_tweaked = x;
+    x_tweaked[31] ^= 1;
+    let point = AffinePoint::from_coordinates_unchecked(&x_tweaked.into(), &y.into()).unwrap();
+    assert!(!bool::from(point.is_on_curve()));
+
+    let mut y_tweaked = y;
+    y_tweaked[31] ^= 1;
+    let point = AffinePoint::from_coordinates_unchecked(&x.into(), &y_tweaked.into()).unwrap();
+    assert!(!bool::from(point.is_on_curve()));
+
+    // Coordinates outside the field are rejected outright
+    assert!(bool::from(
+        AffinePoint::from_coordinates_unchecked(&[0xff; 32].into(), &y.into()).is_none()
+    ));
+}
+
+#[test]
+fn is_torsion_free() {
+    use elliptic_curve::group::cofactor::CofactorGroup;
+    use p256::{ProjectivePoint, Scalar};
+
+    assert!(bool::from(AffinePoint::GENERATOR.is_torsion_free()));
+    assert!(bool::from(AffinePoint::IDENTITY.is_torsion_free()));
+
+    for k in [2u64, 3, 0xdead_beef] {
+        let point = ProjectivePoint::GENERATOR * Scalar::from(k);
+        assert!(bool::from(point.to_affine().is_torsion_free()));
+        assert!(bool::from(point.is_torsion_free()));
+    }
+
+    // Off-curve points are not in the subgroup
+    let x = hex!("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296");
+    let mut y = hex!("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5");
+    y[31] ^= 1;
+    let point = AffinePoint::from_coordinates_unchecked(&x.into(), &y.into()).unwrap();
+    assert!(!bool::from(point.is_torsion_free()));
+}
+
+#[test]
+fn typed_point_encodings() {
+    use p256::{HybridPoint, UncompressedPoint, ValidatedCompressedPoint};
+
+    let mut hybrid_basepoint = [0u8; 65];
+    hybrid_basepoint.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    hybrid_basepoint[0] = 0x07;
+
+    let compressed = ValidatedCompressedPoint::try_from(COMPRESSED_BASEPOINT).unwrap();
+    let uncompressed = UncompressedPoint::try_from(UNCOMPRESSED_BASEPOINT).unwrap();
+    let hybrid = HybridPoint::try_from(&hybrid_basepoint[..]).unwrap();
+
+    assert_eq!(compressed.as_ref(), COMPRESSED_BASEPOINT);
+    assert_eq!(uncompressed.as_ref(), UNCOMPRESSED_BASEPOINT);
+    assert_eq!(hybrid.as_bytes(), &hybrid_basepoint);
+
+    // Conversions to and from `AffinePoint`
+    for point in [
+        AffinePoint::from(compressed),
+        AffinePoint::from(uncompressed),
+        AffinePoint::from(hybrid),
+    ] {
+        assert_eq!(point, AffinePoint::GENERATOR);
+    }
+
+    assert_eq!(
+        ValidatedCompressedPoint::try_from(AffinePoint::GENERATOR),
+        Ok(compressed)
+    );
+    assert_eq!(
+        UncompressedPoint::try_from(AffinePoint::GENERATOR),
+        Ok(uncompressed)
+    );
+    assert_eq!(HybridPoint::try_from(AffinePoint::GENERATOR), Ok(hybrid));
+
+    // Conversions among the forms
+    assert_eq!(UncompressedPoint::from(compressed), uncompressed);
+    assert_eq!(HybridPoint::from(compressed), hybrid);
+    assert_eq!(ValidatedCompressedPoint::from(uncompressed), compressed);
+    assert_eq!(HybridPoint::from(uncompressed), hybrid);
+    assert_eq!(ValidatedCompressedPoint::from(hybrid), compressed);
+    assert_eq!(UncompressedPoint::from(hybrid), uncompressed);
+
+    // Conversions to and from `EncodedPoint`
+    let encoded_compressed = EncodedPoint::from_bytes(COMPRESSED_BASEPOINT).unwrap();
+    let encoded_uncompressed = EncodedPoint::from_bytes(UNCOMPRESSED_BASEPOINT).unwrap();
+    assert_eq!(EncodedPoint::from(compressed), encoded_compressed);
+    assert_eq!(EncodedPoint::from(uncompressed), encoded_uncompressed);
+    assert_eq!(EncodedPoint::from(hybrid), encoded_uncompressed);
+
+    for encoded in [encoded_compressed, encoded_uncompressed] {
+        assert_eq!(ValidatedCompressedPoint::try_from(&encoded), Ok(compressed));
+        assert_eq!(UncompressedPoint::try_from(&encoded), Ok(uncompressed));
+        assert_eq!(HybridPoint::try_from(encoded), Ok(hybrid));
+    }
+
+    // The identity has none of these encodings
+    assert!(ValidatedCompressedPoint::try_from(AffinePoint::IDENTITY).is_err());
+    assert!(UncompressedPoint::try_from(EncodedPoint::identity()).is_err());
+    assert!(HybridPoint::try_from(&AffinePoint::IDENTITY).is_err());
+}
+
+#[test]
+fn typed_point_encodings_reject_other_forms() {
+    use p256::{HybridPoint, UncompressedPoint, ValidatedCompressedPoint};
+
+    let mut hybrid_basepoint = [0u8; 65];
+    hybrid_basepoint.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    hybrid_basepoint[0] = 0x07;
+
+    assert!(ValidatedCompressedPoint::try_from(UNCOMPRESSED_BASEPOINT).is_err());
+    assert!(UncompressedPoint::try_from(COMPRESSED_BASEPOINT).is_err());
+    assert!(UncompressedPoint::try_from(&hybrid_basepoint[..]).is_err());
+    assert!(HybridPoint::try_from(UNCOMPRESSED_BASEPOINT).is_err());
+
+    // Tag parity must match `y`
+    hybrid_basepoint[0] = 0x06;
+    assert!(HybridPoint::try_from(&hybrid_basepoint[..]).is_err());
+
+    // Points must be on the curve
+    let mut bytes = [0xff; 33];
+    bytes[0] = 0x02;
+    assert!(ValidatedCompressedPoint::try_from(&bytes[..]).is_err());
+
+    let mut uncompressed = [0u8; 65];
+    uncompressed.copy_from_slice(UNCOMPRESSED_BASEPOINT);
+    uncompressed[64] ^= 1;
+    assert!(UncompressedPoint::try_from(&uncompressed[..]).is_err());
+    uncompressed[0] = 0x07;
+    assert!(HybridPoint::try_from(&uncompressed[..]).is_err());
+}
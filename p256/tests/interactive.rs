@@ -0,0 +1,121 @@
+//! Interactive two-party signing tests.
+
+#![cfg(feature = "interactive")]
+
+use p256::{
+    elliptic_curve::{rand_core::OsRng, Field},
+    interactive::{self, NoncePair},
+    FieldBytes, ProjectivePoint, Scalar,
+};
+use sha2::{Digest, Sha256};
+
+/// A party holding an additive share of the signing key.
+struct Party {
+    key_share: Scalar,
+    public_share: ProjectivePoint,
+}
+
+impl Party {
+    fn new() -> Self {
+        let key_share = Scalar::random(&mut OsRng);
+        Self {
+            key_share,
+            public_share: ProjectivePoint::GENERATOR * key_share,
+        }
+    }
+}
+
+/// Commitment to a public nonce point.
+fn commit(point: &ProjectivePoint) -> [u8; 32] {
+    use p256::elliptic_curve::group::GroupEncoding;
+    Sha256::digest(point.to_bytes()).into()
+}
+
+#[test]
+fn two_party_signature() {
+    let alice = Party::new();
+    let bob = Party::new();
+    let public_key = (alice.public_share + bob.public_share).to_affine();
+
+    let z = FieldBytes::from(Sha256::digest(b"two-party message"));
+
+    // Round 1: each party generates a nonce and publishes a commitment to it
+    let alice_k = NoncePair::generate(&mut OsRng);
+    let bob_k = NoncePair::generate(&mut OsRng);
+    let (alice_r, bob_r) = (alice_k.public_nonce(), bob_k.public_nonce());
+    let (alice_commitment, bob_commitment) = (commit(&alice_r), commit(&bob_r));
+
+    // Round 2: the nonce points are revealed, checked and combined
+    assert_eq!(commit(&alice_r), alice_commitment);
+    assert_eq!(commit(&bob_r), bob_commitment);
+    let r = alice_r + bob_r;
+
+    let alice_s = alice_k.partial_sign(&alice.key_share, &public_key, &z, &r);
+    let bob_s = bob_k.partial_sign(&bob.key_share, &public_key, &z, &r);
+    let s = alice_s + bob_s;
+
+    assert!(interactive::verify(&public_key, &z, &r, &s));
+
+    // A single partial signature isn't a valid signature
+    assert!(!interactive::verify(&public_key, &z, &r, &alice_s));
+
+    // ...and the signature doesn't verify for another message or key
+    let other_z = FieldBytes::from(Sha256::digest(b"other message"));
+    assert!(!interactive::verify(&public_key, &other_z, &r, &s));
+    assert!(!interactive::verify(
+        &alice.public_share.to_affine(),
+        &z,
+        &r,
+        &s
+    ));
+}
+
+#[test]
+fn partial_signatures_are_linear() {
+    let party = Party::new();
+    let public_key = party.public_share.to_affine();
+    let k = NoncePair::generate(&mut OsRng);
+    let r = k.public_nonce();
+    let z = FieldBytes::from([0x42; 32]);
+
+    // A single party is just plain Schnorr: `s·G = R + e·P`
+    let s = k.partial_sign(&party.key_share, &public_key, &z, &r);
+    let e = interactive::challenge(&r, &public_key, &z);
+    assert_eq!(ProjectivePoint::GENERATOR * s, r + party.public_share * e);
+    assert!(interactive::verify(&public_key, &z, &r, &s));
+}
+
+#[test]
+fn challenge_commits_to_public_key() {
+    let (alice, bob) = (Party::new(), Party::new());
+    let r = NoncePair::generate(&mut OsRng).public_nonce();
+    let z = FieldBytes::from([0x42; 32]);
+
+    assert_ne!(
+        interactive::challenge(&r, &alice.public_share.to_affine(), &z),
+        interactive::challenge(&r, &bob.public_share.to_affine(), &z)
+    );
+}
+
+#[test]
+fn rejects_identity() {
+    let party = Party::new();
+    let z = FieldBytes::from([0x42; 32]);
+    let identity = ProjectivePoint::IDENTITY;
+
+    // `s = e·d` satisfies the equation for `R = O`
+    let s =
+        interactive::challenge(&identity, &party.public_share.to_affine(), &z) * party.key_share;
+    assert!(!interactive::verify(
+        &party.public_share.to_affine(),
+        &z,
+        &identity,
+        &s
+    ));
+    assert!(!interactive::verify(
+        &identity.to_affine(),
+        &z,
+        &identity,
+        &Scalar::ZERO
+    ));
+}
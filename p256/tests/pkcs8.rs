@@ -97,3 +97,81 @@ fn encode_pkcs8_public_key_to_pem() {
     let reencoded_public_key = original_public_key.to_string();
     assert_eq!(reencoded_public_key.as_str(), PKCS8_PUBLIC_KEY_PEM);
 }
+
+#[test]
+#[cfg(all(feature = "ecdsa", feature = "pem"))]
+fn verifying_key_to_spki() {
+    use p256::{ecdsa::VerifyingKey, pkcs8::der::Encode};
+
+    let verifying_key = VerifyingKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    let spki = verifying_key.to_spki();
+    assert_eq!(
+        spki.subject_public_key(),
+        verifying_key.to_encoded_point(false).as_bytes()
+    );
+
+    let der = p256::PublicKey::from(&verifying_key)
+        .to_public_key_der()
+        .unwrap();
+    assert_eq!(spki.to_vec().unwrap(), der.as_ref());
+    assert_eq!(spki.as_spki().to_vec().unwrap(), der.as_ref());
+
+    let mut buf = [0u8; 91];
+    assert_eq!(
+        spki.encode_to_slice(&mut buf).unwrap(),
+        &PKCS8_PUBLIC_KEY_DER[..]
+    );
+
+    assert_eq!(VerifyingKey::try_from(&spki).unwrap(), verifying_key);
+}
+
+#[test]
+#[cfg(feature = "ecdsa")]
+fn verifying_key_from_spki_if_p256() {
+    use p256::{
+        ecdsa::VerifyingKey,
+        elliptic_curve::ALGORITHM_OID,
+        pkcs8::{AlgorithmIdentifier, ObjectIdentifier, SubjectPublicKeyInfo},
+        NistP256,
+    };
+
+    let spki = SubjectPublicKeyInfo::try_from(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert!(NistP256::matches_oid(
+        &spki.algorithm.parameters_oid().unwrap()
+    ));
+    assert_eq!(
+        VerifyingKey::from_spki_if_p256(spki).unwrap(),
+        Some(VerifyingKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap())
+    );
+
+    // secp384r1 key
+    let secp384r1 = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+    assert!(!NistP256::matches_oid(&secp384r1));
+    let p384_key = [4u8; 97];
+    let p384_spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: ALGORITHM_OID,
+            parameters: Some((&secp384r1).into()),
+        },
+        subject_public_key: &p384_key,
+    };
+    assert_eq!(VerifyingKey::from_spki_if_p256(p384_spki).unwrap(), None);
+
+    // Ed25519 key
+    let ed25519_spki = SubjectPublicKeyInfo {
+        algorithm: AlgorithmIdentifier {
+            oid: ObjectIdentifier::new_unwrap("1.3.101.112"),
+            parameters: None,
+        },
+        subject_public_key: &[0; 32],
+    };
+    assert_eq!(VerifyingKey::from_spki_if_p256(ed25519_spki).unwrap(), None);
+
+    // P-256 SPKIs must still be well-formed
+    let mut malformed = spki;
+    malformed.subject_public_key = &p384_key[..65];
+    assert!(VerifyingKey::from_spki_if_p256(malformed).is_err());
+
+    malformed.algorithm.parameters = None;
+    assert!(VerifyingKey::from_spki_if_p256(malformed).is_err());
+}
@@ -1,13 +1,15 @@
 //! Projective arithmetic tests.
 
 #![cfg(all(feature = "arithmetic", feature = "test-vectors"))]
+#![allow(clippy::needless_range_loop, clippy::op_ref)]
 
 use elliptic_curve::{
     group::{ff::PrimeField, GroupEncoding},
-    sec1::{self, ToEncodedPoint},
+    sec1::{self, FromEncodedPoint, ToEncodedPoint},
 };
+use hex_literal::hex;
 use p256::test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS};
-use p256::{AffinePoint, ProjectivePoint, Scalar};
+use p256::{AffinePoint, PrecomputedPoint, ProjectivePoint, Scalar};
 
 /// Assert that the provided projective point matches the given test vector.
 // TODO(tarcieri): use coordinate APIs. See zkcrypto/group#30
@@ -153,8 +155,364 @@ fn test_vector_scalar_mult() {
     }
 }
 
+/// Rebuild the multiples of the generator used by fixed-base multiplication
+/// from scratch, and check them against the shipped constants.
+#[test]
+fn generator_table() {
+    // SEC 2 v2, section 2.4.2
+    assert_point_eq!(
+        ProjectivePoint::GENERATOR,
+        (
+            hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+            hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5")
+        )
+    );
+    assert_eq!(
+        ProjectivePoint::GENERATOR.to_affine(),
+        AffinePoint::GENERATOR
+    );
+
+    let table = PrecomputedPoint::new(&ProjectivePoint::GENERATOR);
+    let mut expected = ProjectivePoint::IDENTITY;
+
+    for i in 0..16u64 {
+        let actual = table.mul(&Scalar::from(i));
+        assert_eq!(
+            actual.to_affine().to_encoded_point(false),
+            expected.to_affine().to_encoded_point(false),
+            "[{}]G",
+            i
+        );
+
+        if i > 0 {
+            assert_point_eq!(actual, ADD_TEST_VECTORS[i as usize - 1]);
+        }
+
+        expected += ProjectivePoint::GENERATOR;
+    }
+}
+
+#[test]
+fn precomputed_point_mul() {
+    let generator = PrecomputedPoint::new(&ProjectivePoint::GENERATOR);
+    assert_eq!(generator.point(), ProjectivePoint::GENERATOR);
+
+    for (k, coords) in MUL_TEST_VECTORS
+        .iter()
+        .cloned()
+        .map(|(k, x, y)| (Scalar::from_repr(k.into()).unwrap(), (x, y)))
+    {
+        assert_point_eq!(generator.mul(&k), coords);
+    }
+
+    let point = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+    let precomputed = PrecomputedPoint::from(point);
+    assert_eq!(precomputed.mul(&Scalar::ZERO), ProjectivePoint::IDENTITY);
+    assert_eq!(precomputed.mul(&-Scalar::ONE), -point);
+
+    let (k, l) = (Scalar::from(3u64), -Scalar::from(5u64));
+    assert_eq!(
+        PrecomputedPoint::lincomb_vartime(&generator, &k, &precomputed, &l),
+        ProjectivePoint::GENERATOR * k + point * l
+    );
+}
+
+#[test]
+fn is_on_curve() {
+    assert!(bool::from(ProjectivePoint::IDENTITY.is_on_curve()));
+    assert!(bool::from(ProjectivePoint::GENERATOR.is_on_curve()));
+
+    // Points with `Z != 1`
+    let mut point = ProjectivePoint::GENERATOR;
+    for _ in 0..8 {
+        point = point.double() + ProjectivePoint::GENERATOR;
+        assert!(bool::from(point.is_on_curve()));
+        assert!(bool::from((-point).is_on_curve()));
+    }
+
+    // Corrupt the least significant bit of `y`
+    let mut y = hex!("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5");
+    y[31] ^= 1;
+    let x = hex!("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296");
+    let corrupted = ProjectivePoint::from(
+        AffinePoint::from_coordinates_unchecked(&x.into(), &y.into()).unwrap(),
+    );
+    assert!(!bool::from(corrupted.is_on_curve()));
+
+    // Caught by the invariant checks otherwise, see `strict_invariants`
+    #[cfg(not(all(feature = "strict-invariants", debug_assertions)))]
+    assert!(!bool::from(corrupted.double().is_on_curve()));
+}
+
+#[cfg(all(feature = "strict-invariants", debug_assertions))]
+#[test]
+#[should_panic(expected = "not on the curve")]
+fn strict_invariants() {
+    // Corrupt the least significant bit of `y`
+    let mut y = hex!("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5");
+    y[31] ^= 1;
+    let x = hex!("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296");
+    let corrupted = ProjectivePoint::from(
+        AffinePoint::from_coordinates_unchecked(&x.into(), &y.into()).unwrap(),
+    );
+
+    let _ = corrupted + ProjectivePoint::GENERATOR;
+}
+
+#[test]
+fn random() {
+    use rand_core::OsRng;
+
+    let p = ProjectivePoint::random(&mut OsRng);
+    let q = ProjectivePoint::random(&mut OsRng);
+
+    for point in [p, q] {
+        assert!(bool::from(point.is_on_curve()));
+        assert_ne!(point, ProjectivePoint::IDENTITY);
+        assert!(bool::from(point.to_affine().is_on_curve()));
+    }
+
+    assert_ne!(p, q);
+}
+
+#[test]
+fn mul_small() {
+    let p = ProjectivePoint::GENERATOR.double();
+
+    assert_eq!(p.mul_small(0), ProjectivePoint::IDENTITY);
+    assert_eq!(p.mul_small(1), p);
+    assert_eq!(p.mul_small(5), p + p + p + p + p);
+
+    for n in [2, 3, 8, 255, u64::MAX] {
+        assert_eq!(p.mul_small(n), p * Scalar::from(n));
+    }
+}
+
 #[test]
 fn projective_identity_to_bytes() {
     // This is technically an invalid SEC1 encoding, but is preferable to panicking.
     assert_eq!([0; 33], ProjectivePoint::IDENTITY.to_bytes().as_slice());
 }
+
+/// Exercise the `group` traits the way curve-generic protocol code would.
+fn generic_group_laws<G>(mut rng: impl rand_core::RngCore) -> G
+where
+    G: elliptic_curve::group::prime::PrimeGroup,
+{
+    use elliptic_curve::group::ff::Field;
+
+    let a = G::random(&mut rng);
+    let b = G::random(&mut rng);
+    let k = G::Scalar::random(&mut rng);
+
+    assert!(bool::from(G::identity().is_identity()));
+    assert!(!bool::from(G::generator().is_identity()));
+    assert_eq!(a + G::identity(), a);
+    assert_eq!(a - b + b, a);
+    assert_eq!(a.double(), a + a);
+    assert_eq!((a + b) * k, a * k + b * k);
+    assert_eq!(G::generator() * G::Scalar::one(), G::generator());
+
+    let bytes = a.to_bytes();
+    assert_eq!(G::from_bytes(&bytes).unwrap(), a);
+    assert_eq!(G::from_bytes_unchecked(&bytes).unwrap(), a);
+
+    a
+}
+
+#[test]
+fn group_traits() {
+    use rand_core::OsRng;
+
+    let point = generic_group_laws::<ProjectivePoint>(&mut OsRng);
+
+    // `GroupEncoding` uses the compressed SEC1 encoding
+    assert_eq!(
+        point.to_bytes().as_slice(),
+        point.to_affine().to_encoded_point(true).as_bytes()
+    );
+}
+
+#[test]
+fn add_incomplete_matches_add_complete() {
+    let generator = ProjectivePoint::GENERATOR;
+    let mut p = generator.double();
+
+    for _ in 0..ADD_TEST_VECTORS.len() {
+        // Use a non-normalized `Z` coordinate for one of the inputs
+        let q = p.double() - &p;
+        assert_eq!(p.add_incomplete(&generator), p.add_complete(&generator));
+        assert_eq!(q.add_incomplete(&generator), p + &generator);
+        p += &generator;
+    }
+}
+
+#[test]
+fn add_incomplete_inverse() {
+    // The incomplete formula handles `P + (-P)` (but not `P + P`)
+    let generator = ProjectivePoint::GENERATOR;
+    assert_eq!(
+        generator.add_incomplete(&-generator),
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn select_point() {
+    let mut table = [ProjectivePoint::IDENTITY; 20];
+    let mut p = ProjectivePoint::GENERATOR;
+    for entry in table.iter_mut() {
+        *entry = p;
+        p = p.double();
+    }
+
+    for (i, entry) in table.iter().enumerate() {
+        assert_eq!(ProjectivePoint::select_point(&table, i as u8), *entry);
+    }
+
+    assert_eq!(
+        ProjectivePoint::select_point(&table, table.len() as u8),
+        ProjectivePoint::IDENTITY
+    );
+    assert_eq!(
+        ProjectivePoint::select_point(&[], 0),
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn sum_with_identity() {
+    let generator = ProjectivePoint::GENERATOR;
+    let points = [
+        ProjectivePoint::IDENTITY,
+        generator,
+        ProjectivePoint::IDENTITY,
+        generator.double(),
+        ProjectivePoint::IDENTITY,
+    ];
+    let expected = generator * Scalar::from(3u64);
+
+    assert_eq!(ProjectivePoint::sum(points), expected);
+    assert_eq!(ProjectivePoint::sum(points.iter()), expected);
+    assert_eq!(points.iter().sum::<ProjectivePoint>(), expected);
+    assert_eq!(points.into_iter().sum::<ProjectivePoint>(), expected);
+    assert_eq!(
+        ProjectivePoint::sum(core::iter::empty::<ProjectivePoint>()),
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn subtraction_variants() {
+    let p = ProjectivePoint::GENERATOR * Scalar::from(7u64);
+    let q = ProjectivePoint::GENERATOR * Scalar::from(3u64);
+    let p_affine = p.to_affine();
+    let q_affine = q.to_affine();
+    let expected = p + (-q);
+
+    assert_eq!(p - q, expected);
+    assert_eq!(&p - &q, expected);
+    assert_eq!(p - &q, expected);
+
+    assert_eq!(p.sub_mixed(&q_affine), expected);
+    assert_eq!(p - q_affine, expected);
+    assert_eq!(&p - &q_affine, expected);
+    assert_eq!(p - &q_affine, expected);
+
+    assert_eq!(p_affine - q_affine, expected);
+    assert_eq!(&p_affine - &q_affine, expected);
+    assert_eq!(p_affine - &q_affine, expected);
+
+    assert_eq!(p.sub_mixed(&AffinePoint::IDENTITY), p);
+    assert_eq!(p_affine - p_affine, ProjectivePoint::IDENTITY);
+}
+
+#[test]
+fn identity_sec1_round_trip() {
+    for compress in [true, false] {
+        let encoded = ProjectivePoint::IDENTITY.to_encoded_point(compress);
+        assert_eq!(encoded.as_bytes(), &[0x00]);
+
+        let point = ProjectivePoint::from_encoded_point(&encoded).unwrap();
+        assert_eq!(point, ProjectivePoint::IDENTITY);
+    }
+
+    // Non-identity points round-trip through the same path
+    let point = ProjectivePoint::GENERATOR.double();
+    let encoded = point.to_encoded_point(true);
+    assert_eq!(
+        ProjectivePoint::from_encoded_point(&encoded).unwrap(),
+        point
+    );
+}
+
+#[test]
+fn mul_windowed() {
+    let point = ProjectivePoint::GENERATOR.double();
+    let scalars = [
+        Scalar::ZERO,
+        Scalar::ONE,
+        -Scalar::ONE,
+        Scalar::from(0x1234_5678u64),
+        Scalar::from_repr(
+            hex!("c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd").into(),
+        )
+        .unwrap(),
+    ];
+
+    for k in scalars {
+        let expected = point * k;
+        assert_eq!(point.mul_windowed::<1>(&k), expected);
+        assert_eq!(point.mul_windowed::<2>(&k), expected);
+        assert_eq!(point.mul_windowed::<3>(&k), expected);
+        assert_eq!(point.mul_windowed::<4>(&k), expected);
+        assert_eq!(point.mul_windowed::<5>(&k), expected);
+        assert_eq!(point.mul_windowed::<6>(&k), expected);
+    }
+}
+
+#[test]
+fn batch_normalize() {
+    use elliptic_curve::group::Curve;
+
+    let g = ProjectivePoint::GENERATOR;
+    let points = [
+        g,
+        ProjectivePoint::IDENTITY,
+        g.double() + g,
+        g * Scalar::from(0x1234_5678u64),
+        ProjectivePoint::IDENTITY,
+        -g,
+    ];
+
+    let mut affine = [AffinePoint::GENERATOR; 6];
+    ProjectivePoint::batch_normalize(&points, &mut affine);
+
+    for (point, affine) in points.iter().zip(&affine) {
+        assert_eq!(*affine, point.to_affine());
+    }
+
+    ProjectivePoint::batch_normalize(&[], &mut []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn to_affine_batch() {
+    let g = ProjectivePoint::GENERATOR;
+    let points = [
+        ProjectivePoint::IDENTITY,
+        g,
+        g.double(),
+        g * Scalar::from(0xdead_beefu64),
+        ProjectivePoint::IDENTITY,
+    ];
+
+    let affine = ProjectivePoint::to_affine_batch(&points);
+    assert_eq!(affine.len(), points.len());
+
+    for (point, affine) in points.iter().zip(&affine) {
+        assert_eq!(*affine, point.to_affine());
+    }
+
+    assert!(ProjectivePoint::to_affine_batch(&[]).is_empty());
+}
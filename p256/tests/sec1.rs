@@ -0,0 +1,54 @@
+//! SEC1 private key tests
+
+#![cfg(all(feature = "ecdsa", feature = "pem"))]
+
+use hex_literal::hex;
+use p256::{ecdsa::SigningKey, elliptic_curve::sec1::ToEncodedPoint, pkcs8::LineEnding};
+
+/// PEM-encoded SEC1 private key generated with
+/// `openssl ecparam -name prime256v1 -genkey -noout`
+const SEC1_PRIVATE_KEY_PEM: &str = include_str!("examples/sec1-private-key.pem");
+
+/// Same key as above with the optional public key removed via
+/// `openssl ec -no_public`
+const SEC1_PRIVATE_KEY_NO_PUBLIC_PEM: &str =
+    include_str!("examples/sec1-private-key-no-public.pem");
+
+/// secp256k1 key whose scalar happens to be a valid P-256 scalar
+const SEC1_SECP256K1_PRIVATE_KEY_PEM: &str =
+    include_str!("examples/sec1-secp256k1-private-key.pem");
+
+#[test]
+fn decode_sec1_private_key_from_pem() {
+    let signing_key = SigningKey::from_sec1_pem(SEC1_PRIVATE_KEY_PEM).unwrap();
+    let expected_scalar = hex!("ae612f968e9b8f6df0d52bd1c935f4fb166436fc483a4a3f0b477314b55453db");
+    assert_eq!(signing_key.to_bytes().as_slice(), &expected_scalar[..]);
+
+    let expected_sec1_point = hex!("041f7a489b2e101c7548a5321f96e584e8fcda6dc8d166de7078e41debdf4b824489c92c7da620574268f5aad8c8e2b65046a414a531692564bde962627dfc2c35");
+    assert_eq!(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .as_bytes(),
+        &expected_sec1_point[..]
+    );
+}
+
+#[test]
+fn decode_sec1_private_key_without_public_key_from_pem() {
+    let signing_key = SigningKey::from_sec1_pem(SEC1_PRIVATE_KEY_NO_PUBLIC_PEM).unwrap();
+    let expected = SigningKey::from_sec1_pem(SEC1_PRIVATE_KEY_PEM).unwrap();
+    assert_eq!(signing_key, expected);
+}
+
+#[test]
+fn reject_sec1_private_key_for_other_curve() {
+    assert!(SigningKey::from_sec1_pem(SEC1_SECP256K1_PRIVATE_KEY_PEM).is_err());
+}
+
+#[test]
+fn encode_sec1_private_key_to_pem() {
+    let signing_key = SigningKey::from_sec1_pem(SEC1_PRIVATE_KEY_PEM).unwrap();
+    let pem = signing_key.to_sec1_pem(LineEnding::LF).unwrap();
+    assert_eq!(&*pem, SEC1_PRIVATE_KEY_PEM);
+}
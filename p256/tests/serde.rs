@@ -0,0 +1,103 @@
+//! `serde` tests.
+
+#![cfg(all(feature = "ecdsa", feature = "serde"))]
+
+use hex_literal::hex;
+use p256::ecdsa::{recoverable, Signature};
+
+/// Recoverable signature from the recovery test vectors.
+const RECOVERABLE_SIGNATURE: [u8; 65] = hex!(
+    "45fef169243c0163c2e86c864a37973c56733a152303626eafe971b5524e8d4e
+     dbd3353771de5a5ef931b53736a12ed6e65fd41a193ca87f165d852f888a03c2
+     00"
+);
+
+#[test]
+fn signature_postcard() {
+    let sig = Signature::try_from(&RECOVERABLE_SIGNATURE[..64]).unwrap();
+
+    let mut buf = [0u8; 128];
+    let encoded = postcard::to_slice(&sig, &mut buf).unwrap();
+    assert_eq!(encoded, &RECOVERABLE_SIGNATURE[..64]);
+    assert_eq!(postcard::from_bytes::<Signature>(encoded).unwrap(), sig);
+}
+
+#[test]
+fn recoverable_signature_postcard() {
+    let sig = recoverable::Signature::try_from(&RECOVERABLE_SIGNATURE[..]).unwrap();
+
+    let mut buf = [0u8; 128];
+    let encoded = postcard::to_slice(&sig, &mut buf).unwrap();
+    assert_eq!(encoded, &RECOVERABLE_SIGNATURE[..]);
+    assert_eq!(
+        postcard::from_bytes::<recoverable::Signature>(encoded).unwrap(),
+        sig
+    );
+
+    // Invalid recovery IDs are rejected
+    let mut bytes = RECOVERABLE_SIGNATURE;
+    bytes[64] = 2;
+    assert!(postcard::from_bytes::<recoverable::Signature>(&bytes).is_err());
+}
+
+#[test]
+fn verifying_key_postcard() {
+    use p256::ecdsa::VerifyingKey;
+
+    let pk = hex!("02c156afee1ce52ef83a0dd168c1144eb20008697e6664fa132ba23c128cce8055");
+    let verifying_key = VerifyingKey::from_sec1_bytes(&pk).unwrap();
+
+    let mut buf = [0u8; 128];
+    let encoded = postcard::to_slice(&verifying_key, &mut buf).unwrap();
+    assert_eq!(encoded, &pk);
+    assert_eq!(
+        postcard::from_bytes::<VerifyingKey>(encoded).unwrap(),
+        verifying_key
+    );
+}
+
+#[test]
+fn verifying_key_json() {
+    use p256::ecdsa::VerifyingKey;
+
+    let pk = hex!("02c156afee1ce52ef83a0dd168c1144eb20008697e6664fa132ba23c128cce8055");
+    let verifying_key = VerifyingKey::from_sec1_bytes(&pk).unwrap();
+
+    // Hex-encoded SPKI with an uncompressed point, whichever features are on
+    let spki = "3059301306072A8648CE3D020106082A8648CE3D03010703420004\
+                C156AFEE1CE52EF83A0DD168C1144EB20008697E6664FA132BA23C128CCE8055\
+                2F9312DDDC598E5D5B1BE0B127E11C53252CC79C6A3C9A05C73D5E24335F718C";
+    let json = serde_json::to_string(&verifying_key).unwrap();
+    assert_eq!(json, format!("\"{}\"", spki));
+    assert_eq!(
+        serde_json::from_str::<VerifyingKey>(&json).unwrap(),
+        verifying_key
+    );
+
+    #[cfg(feature = "pem")]
+    {
+        use p256::pkcs8::EncodePublicKey;
+        let der = p256::PublicKey::from(&verifying_key)
+            .to_public_key_der()
+            .unwrap();
+        assert_eq!(
+            der.as_bytes(),
+            hex!(
+                "3059301306072A8648CE3D020106082A8648CE3D03010703420004
+                 C156AFEE1CE52EF83A0DD168C1144EB20008697E6664FA132BA23C128CCE8055
+                 2F9312DDDC598E5D5B1BE0B127E11C53252CC79C6A3C9A05C73D5E24335F718C"
+            )
+        );
+    }
+
+    // SPKI with a compressed point is accepted too
+    let compressed = "\"3039301306072A8648CE3D020106082A8648CE3D030107032200\
+                      02C156AFEE1CE52EF83A0DD168C1144EB20008697E6664FA132BA23C128CCE8055\"";
+    assert_eq!(
+        serde_json::from_str::<VerifyingKey>(compressed).unwrap(),
+        verifying_key
+    );
+
+    // ...but not a bare SEC1 point
+    assert!(serde_json::from_str::<VerifyingKey>(&format!("\"{}\"", &spki[52..])).is_err());
+}
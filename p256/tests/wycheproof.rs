@@ -0,0 +1,160 @@
+//! Wycheproof ECDSA test harness.
+//!
+//! Loads the full Wycheproof `ecdsa_secp256r1_sha256_test.json` corpus and
+//! checks every test case against [`DigestVerifier::verify_digest`].
+//!
+//! Wycheproof classifies each case as `valid`, `invalid` or `acceptable`.
+//! Acceptable cases are legal-but-discouraged inputs (e.g. weak parameters
+//! or unusual encodings) which an implementation may either accept or
+//! reject, so both outcomes are allowed for them here.
+
+#![cfg(feature = "ecdsa")]
+
+use p256::ecdsa::{signature::DigestVerifier, Signature, VerifyingKey};
+use serde_crate::Deserialize;
+use sha2::{Digest, Sha256};
+
+/// Wycheproof ECDSA/P-256 verification test vectors (ASN.1 DER signatures)
+const WYCHEPROOF_JSON: &str = include_str!("data/ecdsa_secp256r1_sha256_test.json");
+
+#[derive(Deserialize)]
+#[serde(crate = "serde_crate", rename_all = "camelCase")]
+struct TestFile {
+    number_of_tests: usize,
+    test_groups: Vec<TestGroup>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "serde_crate", rename_all = "camelCase")]
+struct TestGroup {
+    public_key: PublicKey,
+    sha: String,
+    tests: Vec<TestCase>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "serde_crate")]
+struct PublicKey {
+    curve: String,
+    uncompressed: String,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "serde_crate", rename_all = "camelCase")]
+struct TestCase {
+    tc_id: usize,
+    comment: String,
+    flags: Vec<String>,
+    msg: String,
+    sig: String,
+    result: Expectation,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(crate = "serde_crate", rename_all = "lowercase")]
+enum Expectation {
+    Valid,
+    Invalid,
+    Acceptable,
+}
+
+/// Decode a hex string, panicking on malformed input.
+fn hex_decode(s: &str) -> Vec<u8> {
+    assert_eq!(s.len() % 2, 0, "odd-length hex string");
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("invalid hex"))
+        .collect()
+}
+
+/// Verify a single test case, returning whether the signature was accepted.
+fn verify(verifying_key: &VerifyingKey, test: &TestCase) -> bool {
+    let digest = Sha256::new_with_prefix(hex_decode(&test.msg));
+
+    let signature = match Signature::from_der(&hex_decode(&test.sig)) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    let accepted = verifying_key
+        .verify_digest(digest.clone(), &signature)
+        .is_ok();
+
+    // ECDSA signatures are malleable: `(r, -s)` is equally valid
+    let (r, s) = signature.split_scalars();
+    let twin = Signature::from_scalars(*r, -*s).unwrap();
+    assert_eq!(
+        verifying_key.verify_digest(digest, &twin).is_ok(),
+        accepted,
+        "tcId {}: malleated signature verification mismatch",
+        test.tc_id
+    );
+
+    accepted
+}
+
+#[test]
+fn wycheproof() {
+    let file: TestFile = serde_json::from_str(WYCHEPROOF_JSON).unwrap();
+    let mut failures = Vec::new();
+    let mut count = 0;
+
+    for group in &file.test_groups {
+        assert_eq!(group.public_key.curve, "secp256r1");
+        assert_eq!(group.sha, "SHA-256");
+
+        let verifying_key =
+            VerifyingKey::from_sec1_bytes(&hex_decode(&group.public_key.uncompressed)).ok();
+
+        for test in &group.tests {
+            count += 1;
+
+            let accepted = verifying_key
+                .as_ref()
+                .map(|key| verify(key, test))
+                .unwrap_or(false);
+
+            let ok = match test.result {
+                Expectation::Valid => accepted,
+                Expectation::Invalid => !accepted,
+                Expectation::Acceptable => true,
+            };
+
+            if !ok {
+                failures.push(format!(
+                    "tcId {} ({}, flags: {:?}): expected {:?}, accepted: {}",
+                    test.tc_id, test.comment, test.flags, test.result, accepted
+                ));
+            }
+        }
+    }
+
+    assert_eq!(count, file.number_of_tests);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Ensure the corpus covers the DER encoding and arithmetic edge cases we
+/// care about, so a truncated or swapped data file doesn't go unnoticed.
+#[test]
+fn wycheproof_coverage() {
+    let file: TestFile = serde_json::from_str(WYCHEPROOF_JSON).unwrap();
+    let has_flag = |flag: &str| {
+        file.test_groups
+            .iter()
+            .flat_map(|group| &group.tests)
+            .any(|test| test.flags.iter().any(|f| f == flag))
+    };
+
+    for flag in [
+        "BerEncodedSignature",
+        "InvalidEncoding",
+        "MissingZero",
+        "ModifiedInteger",
+        "IntegerOverflow",
+        "RangeCheck",
+        "ArithmeticError",
+        "PointDuplication",
+    ] {
+        assert!(has_flag(flag), "missing test cases flagged {}", flag);
+    }
+}
@@ -0,0 +1,38 @@
+//! X.509 certificate tests
+
+#![cfg(feature = "x509")]
+
+use p256::{ecdsa::VerifyingKey, pkcs8::DecodePublicKey};
+
+/// DER-encoded X.509 leaf certificate with a P-256 key, issued by a P-256 CA
+const LEAF_CERTIFICATE_DER: &[u8] = include_bytes!("examples/x509-p256-leaf-certificate.der");
+
+/// DER-encoded SPKI of the key in [`LEAF_CERTIFICATE_DER`]
+const LEAF_PUBLIC_KEY_DER: &[u8] = include_bytes!("examples/x509-p256-leaf-public-key.der");
+
+/// DER-encoded self-signed X.509 certificate with a P-384 key
+const P384_CERTIFICATE_DER: &[u8] = include_bytes!("examples/x509-p384-certificate.der");
+
+#[test]
+fn verifying_key_from_x509_der() {
+    let verifying_key = VerifyingKey::from_x509_der(LEAF_CERTIFICATE_DER).unwrap();
+    let expected = VerifyingKey::from_public_key_der(LEAF_PUBLIC_KEY_DER).unwrap();
+    assert_eq!(verifying_key, expected);
+}
+
+#[test]
+fn reject_other_curves() {
+    assert!(VerifyingKey::from_x509_der(P384_CERTIFICATE_DER).is_err());
+}
+
+#[test]
+fn reject_malformed_certificates() {
+    let len = LEAF_CERTIFICATE_DER.len();
+    assert!(VerifyingKey::from_x509_der(&LEAF_CERTIFICATE_DER[..len - 1]).is_err());
+    assert!(VerifyingKey::from_x509_der(LEAF_PUBLIC_KEY_DER).is_err());
+    assert!(VerifyingKey::from_x509_der(&[]).is_err());
+
+    let mut trailing = LEAF_CERTIFICATE_DER.to_vec();
+    trailing.push(0);
+    assert!(VerifyingKey::from_x509_der(&trailing).is_err());
+}
@@ -176,20 +176,20 @@ mod tests {
         let one = FieldElement::ONE;
         assert_eq!(one.invert().unwrap(), one);
 
-        let three = one + &one + &one;
+        let three = one + one + one;
         let inv_three = three.invert().unwrap();
-        assert_eq!(three * &inv_three, one);
+        assert_eq!(three * inv_three, one);
 
         let minus_three = -three;
         let inv_minus_three = minus_three.invert().unwrap();
         assert_eq!(inv_minus_three, -inv_three);
-        assert_eq!(three * &inv_minus_three, -one);
+        assert_eq!(three * inv_minus_three, -one);
     }
 
     #[test]
     fn sqrt() {
         let one = FieldElement::ONE;
-        let two = one + &one;
+        let two = one + one;
         let four = two.square();
         assert_eq!(four.sqrt().unwrap(), two);
     }
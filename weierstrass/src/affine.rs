@@ -5,7 +5,7 @@
 use crate::{ProjectivePoint, WeierstrassCurve};
 use core::{
     borrow::Borrow,
-    ops::{Mul, Neg},
+    ops::{Mul, Neg, Sub},
 };
 use elliptic_curve::{
     bigint::ArrayEncoding,
@@ -59,11 +59,57 @@ where
         infinity: 0,
     };
 
+    /// Create an [`AffinePoint`] from big endian affine coordinates *without*
+    /// checking that they satisfy the curve equation.
+    ///
+    /// Returns `None` only if either coordinate is not a canonical field
+    /// element. Points obtained this way should be checked with
+    /// [`AffinePoint::is_on_curve`] before being used for anything else; prefer
+    /// [`FromEncodedPoint`] unless the input is already known to be valid.
+    pub fn from_coordinates_unchecked(x: &FieldBytes<C>, y: &FieldBytes<C>) -> CtOption<Self>
+    where
+        FieldBytes<C>: Copy,
+    {
+        C::FieldElement::from_repr(*x)
+            .and_then(|x| C::FieldElement::from_repr(*y).map(|y| Self { x, y, infinity: 0 }))
+    }
+
+    /// Returns the big endian affine coordinates `(x, y)` of this point, or
+    /// `None` if it is the identity.
+    pub fn coordinates(&self) -> Option<(FieldBytes<C>, FieldBytes<C>)> {
+        if self.is_identity().into() {
+            None
+        } else {
+            Some((self.x.to_repr(), self.y.to_repr()))
+        }
+    }
+
     /// Is this point the point at infinity?
     pub fn is_identity(&self) -> Choice {
         Choice::from(self.infinity)
     }
 
+    /// Do the coordinates of this point satisfy the curve equation
+    /// `y² = x³ + ax + b`?
+    ///
+    /// The identity is always considered to be on the curve.
+    pub fn is_on_curve(&self) -> Choice {
+        let lhs = self.y.square();
+        let rhs = self.x.square() * &self.x + &(C::EQUATION_A * &self.x) + &C::EQUATION_B;
+        lhs.ct_eq(&rhs) | self.is_identity()
+    }
+
+    /// Is this point in the prime-order subgroup?
+    ///
+    /// The curves supported by this crate have prime order, i.e. cofactor 1,
+    /// so every point on the curve is in the subgroup and this is equivalent
+    /// to [`AffinePoint::is_on_curve`]. It is provided so that code shared
+    /// with curves which do have a cofactor can perform the same check
+    /// uniformly.
+    pub fn is_torsion_free(&self) -> Choice {
+        self.is_on_curve()
+    }
+
     /// Indicates the parity of coordinate Y.
     pub fn is_y_odd(self) -> bool {
         self.y.is_odd().into()
@@ -130,6 +176,12 @@ where
     C: WeierstrassCurve,
     FieldBytes<C>: Copy,
 {
+    /// Decompress the point with the given x-coordinate and `y` parity.
+    ///
+    /// The result is never the identity, which has no x-coordinate: it is
+    /// either a point on the curve or `None`. Decoding the SEC1 identity
+    /// encoding is handled separately, by [`FromEncodedPoint`] and
+    /// [`GroupEncoding`], and rejected by key types.
     fn decompress(x_bytes: &FieldBytes<C>, y_is_odd: Choice) -> CtOption<Self> {
         C::FieldElement::from_repr(*x_bytes).and_then(|x| {
             let alpha = x * &x * &x + &(C::EQUATION_A * &x) + &C::EQUATION_B;
@@ -170,9 +222,13 @@ where
     /// Attempts to parse the given [`EncodedPoint`] as an SEC1-encoded
     /// [`AffinePoint`].
     ///
+    /// The SEC1 identity encoding (a single `0x00` byte) decodes to
+    /// [`AffinePoint::IDENTITY`]. Key types such as [`PublicKey`] reject the
+    /// identity separately; at the point level it is a valid group element.
+    ///
     /// # Returns
     ///
-    /// `None` value if `encoded_point` is not on the secp384r1 curve.
+    /// `None` value if `encoded_point` is not on the curve.
     fn from_encoded_point(encoded_point: &EncodedPoint<C>) -> CtOption<Self> {
         match encoded_point.coordinates() {
             sec1::Coordinates::Identity => CtOption::new(Self::IDENTITY, 1.into()),
@@ -339,6 +395,10 @@ where
     CompressedPoint<C>: Copy,
     <UncompressedPointSize<C> as ArrayLength<u8>>::ArrayType: Copy,
 {
+    /// Serialize this point as SEC1.
+    ///
+    /// The identity is encoded as a single `0x00` byte regardless of
+    /// `compress`, which [`FromEncodedPoint`] decodes back to the identity.
     fn to_encoded_point(&self, compress: bool) -> EncodedPoint<C> {
         EncodedPoint::<C>::conditional_select(
             &EncodedPoint::<C>::from_affine_coordinates(
@@ -444,6 +504,39 @@ where
     }
 }
 
+impl<C> Sub<AffinePoint<C>> for AffinePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    type Output = ProjectivePoint<C>;
+
+    fn sub(self, other: AffinePoint<C>) -> ProjectivePoint<C> {
+        ProjectivePoint::from(self).sub_mixed(&other)
+    }
+}
+
+impl<C> Sub<&AffinePoint<C>> for &AffinePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    type Output = ProjectivePoint<C>;
+
+    fn sub(self, other: &AffinePoint<C>) -> ProjectivePoint<C> {
+        ProjectivePoint::from(self).sub_mixed(other)
+    }
+}
+
+impl<C> Sub<&AffinePoint<C>> for AffinePoint<C>
+where
+    C: WeierstrassCurve,
+{
+    type Output = ProjectivePoint<C>;
+
+    fn sub(self, other: &AffinePoint<C>) -> ProjectivePoint<C> {
+        ProjectivePoint::from(self).sub_mixed(other)
+    }
+}
+
 //
 // serde support
 //
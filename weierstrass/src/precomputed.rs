@@ -0,0 +1,156 @@
+//! Precomputed window tables for repeated scalar multiplication.
+
+#![allow(clippy::needless_range_loop)]
+
+use crate::{ProjectivePoint, WeierstrassCurve};
+use elliptic_curve::{
+    bigint::{ArrayEncoding, Encoding},
+    subtle::{ConditionallySelectable, ConstantTimeEq},
+    Scalar,
+};
+
+/// Compile-time check that a window of `W` bits is supported.
+pub(crate) struct Window<const W: usize>;
+
+impl<const W: usize> Window<W> {
+    /// Fails to evaluate (and hence to compile) unless `W` is in `1..=6`.
+    pub(crate) const SUPPORTED: () = assert!(W >= 1 && W <= 6, "window size must be in 1..=6");
+}
+
+/// Curve point along with a table of its small multiples, for computing many
+/// scalar multiplications of the same point.
+///
+/// The table holds `N = 2^W` points for a window of `W` bits, with `W` in
+/// `1..=6` (see [`ProjectivePoint::mul_windowed`] for the tradeoffs); any
+/// other `N` fails to compile. `point * scalar` uses `N = 16`.
+///
+/// Building the table costs roughly as much as a handful of point additions,
+/// which every call to `point * scalar` otherwise pays again.
+#[derive(Clone, Copy, Debug)]
+pub struct PrecomputedPoint<C: WeierstrassCurve, const N: usize> {
+    /// `table[i] = [i] point` for `i` in `0..N`.
+    table: [ProjectivePoint<C>; N],
+}
+
+impl<C, const N: usize> PrecomputedPoint<C, N>
+where
+    C: WeierstrassCurve,
+{
+    /// Fails to evaluate (and hence to compile) unless `N = 2^W` with `W`
+    /// in `1..=6`.
+    const SUPPORTED: () = assert!(
+        N.is_power_of_two() && N >= 2 && N <= 64,
+        "window table size must be 2^W for W in 1..=6"
+    );
+
+    /// Window size in bits, i.e. `log2(N)`.
+    const WINDOW: usize = N.trailing_zeros() as usize;
+
+    /// Number of windows covering a scalar.
+    const WINDOWS: usize = (C::UInt::BIT_SIZE + Self::WINDOW - 1) / Self::WINDOW;
+
+    /// Compute the window table for the given point.
+    pub fn new(point: &ProjectivePoint<C>) -> Self {
+        let () = Self::SUPPORTED;
+
+        let mut table = [ProjectivePoint::IDENTITY; N];
+        table[1] = *point;
+
+        for i in 2..N {
+            table[i] = if i % 2 == 0 {
+                table[i / 2].double()
+            } else {
+                table[i - 1].add(point)
+            };
+        }
+
+        Self { table }
+    }
+
+    /// Returns the point this table was computed for.
+    pub fn point(&self) -> ProjectivePoint<C> {
+        self.table[1]
+    }
+
+    /// Returns `[k] point`.
+    ///
+    /// Like `point * k`, this runs in constant time with respect to `k`.
+    pub fn mul(&self, k: &Scalar<C>) -> ProjectivePoint<C> {
+        let k = Into::<C::UInt>::into(*k).to_le_byte_array();
+        let mut q = ProjectivePoint::IDENTITY;
+
+        for j in (0..Self::WINDOWS).rev() {
+            for _ in 0..Self::WINDOW {
+                q = q.double();
+            }
+
+            let slot = Self::slot(&k, j) as u64;
+            let mut t = ProjectivePoint::IDENTITY;
+
+            for i in 1..N {
+                t.conditional_assign(&self.table[i], (i as u64).ct_eq(&slot));
+            }
+
+            q = q.add(&t);
+        }
+
+        q
+    }
+
+    /// Returns `[k] x + [l] y`, sharing the doublings between both
+    /// multiplications (a.k.a. Shamir's trick).
+    ///
+    /// This runs in variable time and must only be used with public scalars,
+    /// e.g. when verifying signatures.
+    pub fn lincomb_vartime(x: &Self, k: &Scalar<C>, y: &Self, l: &Scalar<C>) -> ProjectivePoint<C> {
+        let k = Into::<C::UInt>::into(*k).to_le_byte_array();
+        let l = Into::<C::UInt>::into(*l).to_le_byte_array();
+        let mut q = ProjectivePoint::IDENTITY;
+
+        for j in (0..Self::WINDOWS).rev() {
+            for _ in 0..Self::WINDOW {
+                q = q.double();
+            }
+
+            let k_slot = Self::slot(&k, j);
+            let l_slot = Self::slot(&l, j);
+
+            if k_slot != 0 {
+                q = q.add(&x.table[k_slot]);
+            }
+
+            if l_slot != 0 {
+                q = q.add(&y.table[l_slot]);
+            }
+        }
+
+        q
+    }
+
+    /// Returns the `j`-th window of the little endian scalar `k`.
+    fn slot(k: &[u8], j: usize) -> usize {
+        // Windows may straddle a byte boundary, so read two bytes
+        let pos = j * Self::WINDOW;
+        let lo = usize::from(k[pos >> 3]);
+        let hi = k.get((pos >> 3) + 1).map_or(0, |&b| usize::from(b));
+        ((hi << 8 | lo) >> (pos & 7)) & (N - 1)
+    }
+}
+
+impl<C, const N: usize> From<ProjectivePoint<C>> for PrecomputedPoint<C, N>
+where
+    C: WeierstrassCurve,
+{
+    fn from(point: ProjectivePoint<C>) -> Self {
+        Self::new(&point)
+    }
+}
+
+impl<C, const N: usize> From<&ProjectivePoint<C>> for PrecomputedPoint<C, N>
+where
+    C: WeierstrassCurve,
+{
+    fn from(point: &ProjectivePoint<C>) -> Self {
+        Self::new(point)
+    }
+}
@@ -2,14 +2,13 @@
 
 #![allow(clippy::needless_range_loop, clippy::op_ref)]
 
-use crate::{AffinePoint, Field, WeierstrassCurve};
+use crate::{precomputed::Window, AffinePoint, Field, PrecomputedPoint, WeierstrassCurve};
 use core::{
     borrow::Borrow,
     iter::Sum,
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{ArrayEncoding, Encoding},
     generic_array::ArrayLength,
     group::{
         self,
@@ -19,12 +18,18 @@ use elliptic_curve::{
     },
     ops::LinearCombination,
     rand_core::RngCore,
-    sec1::{CompressedPoint, ModulusSize, UncompressedPointSize},
+    sec1::{
+        CompressedPoint, EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint,
+        UncompressedPointSize,
+    },
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
     Error, FieldBytes, FieldSize, PublicKey, Result, Scalar,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// Point on a Weierstrass curve in projective coordinates.
 #[derive(Clone, Copy, Debug)]
 pub struct ProjectivePoint<C: WeierstrassCurve> {
@@ -63,6 +68,20 @@ where
             .unwrap_or(AffinePoint::IDENTITY)
     }
 
+    /// Returns the affine representations of `points`, using a single field
+    /// inversion for the whole batch.
+    ///
+    /// This is an allocating convenience wrapper around
+    /// [`group::Curve::batch_normalize`]. Points at infinity map to
+    /// [`AffinePoint::IDENTITY`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_affine_batch(points: &[Self]) -> Vec<AffinePoint<C>> {
+        let mut affine = alloc::vec![AffinePoint::IDENTITY; points.len()];
+        <Self as group::Curve>::batch_normalize(points, &mut affine);
+        affine
+    }
+
     /// Returns `-self`.
     pub fn neg(&self) -> Self {
         Self {
@@ -100,6 +119,46 @@ where
             y: (yy_p_bzz3 * &yy_m_bzz3) + &(xx3_m_zz3 * &bxz3_part), // 36, 37, 38
             z: (yy_m_bzz3 * &yz_pairs) + &(xy_pairs * &xx3_m_zz3), // 41, 42, 43
         }
+        .check_invariants()
+    }
+
+    /// Returns `self + other` using the complete addition formula.
+    ///
+    /// This is the formula used by the [`Add`] impls: it is constant-time and
+    /// correct for all inputs, including the identity and `self == other`.
+    pub fn add_complete(&self, other: &Self) -> Self {
+        self.add(other)
+    }
+
+    /// Returns `self + other` using the incomplete addition formula from
+    /// Cohen-Miyaji-Ono 1998 ("add-1998-cmo-2").
+    ///
+    /// This formula is cheaper than [`ProjectivePoint::add_complete`] and is
+    /// provided for benchmarking and research purposes.
+    ///
+    /// # Preconditions
+    ///
+    /// Both points must be non-identity and `self` must not be equal to
+    /// `other`. These are *not* checked: for inputs which violate them the
+    /// result is an invalid point. Prefer [`ProjectivePoint::add_complete`]
+    /// (or the [`Add`] impls) unless these conditions are known to hold.
+    pub fn add_incomplete(&self, other: &Self) -> Self {
+        let y1z2 = self.y * &other.z;
+        let x1z2 = self.x * &other.z;
+        let z1z2 = self.z * &other.z;
+        let u = (other.y * &self.z) - &y1z2;
+        let uu = u.square();
+        let v = (other.x * &self.z) - &x1z2;
+        let vv = v.square();
+        let vvv = v * &vv;
+        let r = vv * &x1z2;
+        let a = (uu * &z1z2) - &vvv - &r.double();
+
+        Self {
+            x: v * &a,
+            y: (u * &(r - &a)) - &(vvv * &y1z2),
+            z: vvv * &z1z2,
+        }
     }
 
     /// Returns `self + other`.
@@ -130,7 +189,7 @@ where
             z: (yy_m_bzz3 * &yz_pairs) + &(xy_pairs * &xx3_m_zz3), // 34, 35, 36
         };
         ret.conditional_assign(self, other.is_identity());
-        ret
+        ret.check_invariants()
     }
 
     /// Doubles this point.
@@ -162,61 +221,143 @@ where
         let x = x_frag - &(bxz6_part * &yz2); // 30, 31
         let z = (yz2 * &yy).double().double(); // 32, 33, 34
 
-        Self { x, y, z }
+        Self { x, y, z }.check_invariants()
+    }
+
+    /// Check that the result of a point operation is on the curve.
+    ///
+    /// With the `strict-invariants` feature this panics in debug builds if it
+    /// isn't, to catch bugs in the field arithmetic backend. It is a no-op in
+    /// release builds, and without the feature.
+    #[inline(always)]
+    fn check_invariants(self) -> Self {
+        #[cfg(feature = "strict-invariants")]
+        debug_assert!(
+            bool::from(self.is_on_curve()),
+            "point operation produced a point which is not on the curve"
+        );
+
+        self
+    }
+
+    /// Returns a uniformly random point, computed as `[k] G` for a uniformly
+    /// random scalar `k`.
+    ///
+    /// The result is therefore always in the prime-order subgroup generated
+    /// by [`ProjectivePoint::GENERATOR`] (and is the identity with negligible
+    /// probability). It is *not* obtained by lifting a random `x`-coordinate
+    /// onto the curve: its discrete logarithm is known to whoever can
+    /// reproduce the output of `rng`, so use hash-to-curve instead when a
+    /// point with an unknown discrete logarithm is required.
+    pub fn random(mut rng: impl RngCore) -> Self {
+        Self::GENERATOR * <Scalar<C> as Field>::random(&mut rng)
     }
 
-    /// Returns `self - other`.
-    pub fn sub(&self, other: &Self) -> Self {
-        self.add(&other.neg())
-    }
+    /// Returns `[n] self` for a small integer `n`, e.g. a cofactor, using
+    /// double-and-add without constructing a full [`Scalar`].
+    ///
+    /// This runs in variable time with respect to `n`, which must therefore
+    /// be public.
+    pub fn mul_small(&self, n: u64) -> Self {
+        let mut q = Self::IDENTITY;
 
-    /// Returns `self - other`.
-    fn sub_mixed(&self, other: &AffinePoint<C>) -> Self {
-        self.add_mixed(&other.neg())
-    }
+        for i in (0..(u64::BITS - n.leading_zeros())).rev() {
+            q = q.double();
 
-    /// Returns `[k] self`.
-    fn mul(&self, k: &Scalar<C>) -> Self {
-        let k = Into::<C::UInt>::into(*k).to_le_byte_array();
-
-        let mut pc = [Self::default(); 16];
-        pc[0] = Self::IDENTITY;
-        pc[1] = *self;
-
-        for i in 2..16 {
-            pc[i] = if i % 2 == 0 {
-                pc[i / 2].double()
-            } else {
-                pc[i - 1].add(self)
-            };
+            if (n >> i) & 1 == 1 {
+                q = q.add(self);
+            }
         }
 
-        let mut q = Self::IDENTITY;
-        let mut pos = C::UInt::BIT_SIZE - 4;
+        q
+    }
 
-        loop {
-            let slot = (k[(pos >> 3) as usize] >> (pos & 7)) & 0xf;
+    /// Returns `[k] self` using a fixed window of `W` bits.
+    ///
+    /// `self * k` uses a 4-bit window, which is a good default on most
+    /// targets. Wider windows trade memory for speed: the table of small
+    /// multiples holds `2^W` points (i.e. `2^W * 3` field elements on the
+    /// stack) and takes `2^W - 2` point operations to build, while the main
+    /// loop performs one addition per `W` bits of the scalar. For P-256 this
+    /// is 16/32/64 table entries and 64/52/43 additions for `W` = 4/5/6.
+    ///
+    /// Like `self * k`, this runs in constant time with respect to `k`, and
+    /// the result doesn't depend on `W`.
+    ///
+    /// Using a `W` outside of `1..=6` is a compile-time error.
+    pub fn mul_windowed<const W: usize>(&self, k: &Scalar<C>) -> Self {
+        let () = Window::<W>::SUPPORTED;
+
+        match W {
+            1 => PrecomputedPoint::<C, 2>::new(self).mul(k),
+            2 => PrecomputedPoint::<C, 4>::new(self).mul(k),
+            3 => PrecomputedPoint::<C, 8>::new(self).mul(k),
+            4 => PrecomputedPoint::<C, 16>::new(self).mul(k),
+            5 => PrecomputedPoint::<C, 32>::new(self).mul(k),
+            6 => PrecomputedPoint::<C, 64>::new(self).mul(k),
+            _ => unreachable!(),
+        }
+    }
 
-            let mut t = ProjectivePoint::IDENTITY;
+    /// Do the coordinates of this point satisfy the projective curve
+    /// equation `Y²Z = X³ + aXZ² + bZ³`?
+    ///
+    /// This holds for every representation of a valid point regardless of
+    /// its `Z` coordinate, including the identity. The degenerate
+    /// `(0 : 0 : 0)` is rejected.
+    pub fn is_on_curve(&self) -> Choice {
+        let zz = self.z.square();
+        let lhs = self.y.square() * &self.z;
+        let rhs = self.x.square() * &self.x
+            + &(C::EQUATION_A * &self.x * &zz)
+            + &(C::EQUATION_B * &zz * &self.z);
 
-            for i in 1..16 {
-                t.conditional_assign(
-                    &pc[i],
-                    Choice::from(((slot as usize ^ i).wrapping_sub(1) >> 8) as u8 & 1),
-                );
-            }
+        lhs.ct_eq(&rhs) & !(self.y.is_zero() & self.z.is_zero())
+    }
 
-            q = q.add(&t);
+    /// Returns `self - other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
 
-            if pos == 0 {
-                break;
-            }
+    /// Returns `table[secret_index]`, or the identity if `secret_index` is
+    /// out of range.
+    ///
+    /// Every entry of `table` is scanned and conditionally assigned, so the
+    /// running time depends only on the length of the table and not on the
+    /// value of `secret_index`.
+    pub fn select_point(table: &[Self], secret_index: u8) -> Self {
+        let secret_index = u64::from(secret_index);
+        let mut point = Self::IDENTITY;
 
-            q = q.double().double().double().double();
-            pos -= 4;
+        for (i, entry) in table.iter().enumerate() {
+            point.conditional_assign(entry, (i as u64).ct_eq(&secret_index));
         }
 
-        q
+        point
+    }
+
+    /// Returns the sum of all points yielded by `iter`, which may be either
+    /// owned or borrowed points.
+    ///
+    /// Returns the identity if `iter` is empty.
+    pub fn sum<I>(iter: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        iter.into_iter()
+            .fold(Self::IDENTITY, |acc, point| acc.add(point.borrow()))
+    }
+
+    /// Returns `self - other`.
+    pub fn sub_mixed(&self, other: &AffinePoint<C>) -> Self {
+        self.add_mixed(&other.neg())
+    }
+
+    /// Returns `[k] self`.
+    fn mul(&self, k: &Scalar<C>) -> Self {
+        self.mul_windowed::<4>(k)
     }
 }
 
@@ -325,8 +466,8 @@ where
 {
     type Scalar = Scalar<C>;
 
-    fn random(mut rng: impl RngCore) -> Self {
-        Self::GENERATOR * <Scalar<C> as Field>::random(&mut rng)
+    fn random(rng: impl RngCore) -> Self {
+        Self::random(rng)
     }
 
     fn identity() -> Self {
@@ -341,7 +482,6 @@ where
         self.ct_eq(&Self::IDENTITY)
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         ProjectivePoint::double(self)
     }
@@ -371,6 +511,37 @@ where
     }
 }
 
+impl<C> FromEncodedPoint<C> for ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+    FieldBytes<C>: Copy,
+    FieldSize<C>: ModulusSize,
+    CompressedPoint<C>: Copy,
+{
+    /// Attempts to parse the given [`EncodedPoint`] as an SEC1-encoded
+    /// [`ProjectivePoint`].
+    ///
+    /// As with [`AffinePoint`], the SEC1 identity encoding (a single `0x00`
+    /// byte) decodes to [`ProjectivePoint::IDENTITY`].
+    fn from_encoded_point(p: &EncodedPoint<C>) -> CtOption<Self> {
+        AffinePoint::<C>::from_encoded_point(p).map(Self::from)
+    }
+}
+
+impl<C> ToEncodedPoint<C> for ProjectivePoint<C>
+where
+    C: WeierstrassCurve,
+    FieldSize<C>: ModulusSize,
+    CompressedPoint<C>: Copy,
+    <UncompressedPointSize<C> as ArrayLength<u8>>::ArrayType: Copy,
+{
+    /// Serialize this point as SEC1, encoding the identity as a single `0x00`
+    /// byte.
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint<C> {
+        self.to_affine().to_encoded_point(compress)
+    }
+}
+
 impl<C> group::Curve for ProjectivePoint<C>
 where
     C: WeierstrassCurve,
@@ -380,6 +551,43 @@ where
     fn to_affine(&self) -> AffinePoint<C> {
         ProjectivePoint::to_affine(self)
     }
+
+    /// Converts a batch of points to affine using a single field inversion
+    /// (a.k.a. Montgomery's trick) rather than one per point.
+    ///
+    /// The prefix products of the `Z` coordinates are kept in the `x`
+    /// coordinates of the output, so no scratch space is needed. Points at
+    /// infinity are handled in constant time.
+    fn batch_normalize(p: &[Self], q: &mut [AffinePoint<C>]) {
+        assert_eq!(p.len(), q.len());
+
+        let mut acc = C::ONE;
+
+        for (point, out) in p.iter().zip(q.iter_mut()) {
+            out.x = acc;
+            acc *= C::FieldElement::conditional_select(&point.z, &C::ONE, point.z.is_zero());
+        }
+
+        // `acc` is a product of nonzero elements, so it is invertible
+        let mut acc_inv = acc.invert().unwrap();
+
+        for (point, out) in p.iter().zip(q.iter_mut()).rev() {
+            let is_identity = point.z.is_zero();
+            let z = C::FieldElement::conditional_select(&point.z, &C::ONE, is_identity);
+            let z_inv = acc_inv * &out.x;
+            acc_inv *= z;
+
+            *out = AffinePoint::conditional_select(
+                &AffinePoint {
+                    x: point.x * &z_inv,
+                    y: point.y * &z_inv,
+                    infinity: 0,
+                },
+                &AffinePoint::IDENTITY,
+                is_identity,
+            );
+        }
+    }
 }
 
 impl<C> LinearCombination for ProjectivePoint<C> where C: WeierstrassCurve {}
@@ -547,7 +755,7 @@ where
     C: WeierstrassCurve,
 {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(ProjectivePoint::IDENTITY, |a, b| a + b)
+        ProjectivePoint::sum(iter)
     }
 }
 
@@ -556,7 +764,7 @@ where
     C: WeierstrassCurve,
 {
     fn sum<I: Iterator<Item = &'a ProjectivePoint<C>>>(iter: I) -> Self {
-        iter.cloned().sum()
+        ProjectivePoint::sum(iter)
     }
 }
 
@@ -706,7 +914,7 @@ where
     }
 }
 
-impl<'a, C> Neg for &'a ProjectivePoint<C>
+impl<C> Neg for &ProjectivePoint<C>
 where
     C: WeierstrassCurve,
 {